@@ -174,8 +174,12 @@ export default async function handler(
     const actualBalanceScaled = Math.floor(balanceFormatted * 10 ** 6); // Scale to avoid decimals
     const tokenContractHash =
       BigInt(ethers.keccak256(ethers.toUtf8Bytes(tokenContract))) % 2n ** 254n;
+    // Must match the contract's expected_user_hash: keccak256 of the 20 address bytes mod r
+    // (the BN254 scalar field order; circom reduces every signal mod r)
+    const BN254_SCALAR_MODULUS =
+      21888242871839275222246405745257275088548364400416034343698204186575808495617n;
     const userAddressHash =
-      BigInt(ethers.keccak256(ethers.toUtf8Bytes(userAddress))) % 2n ** 254n;
+      BigInt(ethers.keccak256(ethers.getAddress(userAddress))) % BN254_SCALAR_MODULUS;
 
     console.log("  • Timestamp:", timestamp, `(${new Date(timestamp * 1000).toISOString()})`);
     console.log("  • Balance Scaled:", actualBalanceScaled, "(10^6 scaling)");
@@ -146,6 +146,24 @@ const Home: NextPage = () => {
         "function ownerOf(uint256 token_id) external view returns (address)",
         "function getNextTokenId() external view returns (uint256)",
         "function getMaxProofAge() external view returns (uint256)",
+        // Custom errors of the mint path, so ethers can decode the revert reason
+        "error ZeroRecipient()",
+        "error InvalidPublicInputCount(uint256 expected, uint256 actual)",
+        "error ZeroNullifier()",
+        "error UserHashMismatch(uint256 expected, uint256 actual)",
+        "error InvalidThreshold(uint256 threshold)",
+        "error LiveBalanceTooLow(uint256 balance, uint256 threshold)",
+        "error NullifierAlreadyUsed(uint256 nullifier)",
+        "error OracleCommitmentExpired(uint256 commitment)",
+        "error ProofExpired(uint256 age)",
+        "error ProofFromFuture(uint256 timestamp)",
+        "error InsufficientMintFee(uint256 required, uint256 paid)",
+        "error MintPaused()",
+        "error VerifierNotInitialized()",
+        "error InvalidProof()",
+        "error PublicInputNotInField(uint256 input)",
+        "error ProofPointAtInfinity()",
+        "error ProofPointNotInSubgroup()",
       ];
 
       console.log("📝 Step 1: Creating Contract Instance...");
@@ -14,6 +14,9 @@ template TokenOwnership() {
     signal input min_required_balance;  // Public: minimum balance threshold
     signal input token_contract_hash;   // Public: hash of token contract
     signal input user_address_hash;     // Public: hash of user address
+                                        //   keccak256(20 raw address bytes) mod r, r being the
+                                        //   BN254 scalar field order; the mint contract rejects
+                                        //   any recipient whose hash differs
     signal input timestamp;             // Public: when oracle signed the data
     signal input oracle_commitment;    // Public: oracle's commitment to the balance data
     
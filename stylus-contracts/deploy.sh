@@ -20,16 +20,33 @@ fi
 # Can be overridden by setting MIN_REQUIRED_BALANCE in .env
 MIN_BALANCE=${MIN_REQUIRED_BALANCE:-100000}
 
+# Hash of the trusted setup ceremony transcript the verifying key came from
+# Can be overridden by setting CEREMONY_HASH in .env
+CEREMONY_HASH=${CEREMONY_HASH:-0x0000000000000000000000000000000000000000000000000000000000000000}
+
+# Track nullifiers in bit-packed words instead of one slot each (suits small sequential nullifiers)
+# Can be overridden by setting BITMAP_NULLIFIERS=true in .env
+BITMAP_NULLIFIERS=${BITMAP_NULLIFIERS:-false}
+
+# ERC721 metadata shown by wallets and marketplaces; tokenURI is BASE_URI + token id
+# Can be overridden by setting TOKEN_NAME, TOKEN_SYMBOL and BASE_URI in .env
+TOKEN_NAME=${TOKEN_NAME:-"ZK Balance Credential"}
+TOKEN_SYMBOL=${TOKEN_SYMBOL:-"ZKBC"}
+BASE_URI=${BASE_URI:-""}
+
 echo "Deploying contract with owner: $OWNER_ADDRESS"
 echo "Minimum required balance: $MIN_BALANCE (scaled by 10^6)"
+echo "Setup ceremony hash: $CEREMONY_HASH"
+echo "Bitmap nullifiers: $BITMAP_NULLIFIERS"
+echo "Token: $TOKEN_NAME ($TOKEN_SYMBOL), base URI: $BASE_URI"
 echo "Using RPC: $RPC_URL"
 echo ""
 
 # Deploy the contract
-# Constructor args: (owner_address, min_required_balance)
+# Constructor args: (owner_address, min_required_balance, setup_ceremony_hash, bitmap_nullifiers, name, symbol, base_uri)
 cargo stylus deploy \
     --endpoint="$RPC_URL" \
     --private-key="$PRIVATE_KEY" \
-    --constructor-args "$OWNER_ADDRESS" "$MIN_BALANCE"
+    --constructor-args "$OWNER_ADDRESS" "$MIN_BALANCE" "$CEREMONY_HASH" "$BITMAP_NULLIFIERS" "$TOKEN_NAME" "$TOKEN_SYMBOL" "$BASE_URI"
 
 
@@ -1,17 +1,22 @@
 // at the top of src/lib.rs
 #![allow(unexpected_cfgs)]
 extern crate alloc;
-use alloc::vec::Vec;
-use alloy_primitives::{Address, U256};
+use alloc::{string::String, vec::Vec};
+use alloy_primitives::{keccak256, Address, FixedBytes, U256, U64, U8};
 use stylus_sdk::{
     prelude::*,
+    abi::Bytes,
     call::RawCall,
-    alloy_sol_types::sol,
+    alloy_sol_types::{sol, SolError},
+    stylus_core::log,
 };
 
 mod verifying_key;
 use verifying_key::get_verifying_key;
 
+#[cfg(feature = "poseidon")]
+mod poseidon;
+
 type G1Point = [u8; 64];   // 32 bytes x + 32 bytes y
 type G2Point = [u8; 128];  // 32 bytes x0 + 32 bytes x1 + 32 bytes y0 + 32 bytes y1
 type Scalar = [u8; 32];    // 32 bytes for field element
@@ -26,18 +31,127 @@ sol_interface! {
             uint64 destinationChainSelector,
             address receiver,
             string calldata text
-        ) external returns (bytes32 messageId);
+        ) external payable returns (bytes32 messageId);
+
+        function getFee(
+            uint64 destinationChainSelector,
+            address receiver,
+            string calldata text
+        ) external view returns (uint256 fee);
+    }
+
+    interface IArbSys {
+        function arbBlockNumber() external view returns (uint256);
+        function arbBlockHash(uint256 arbBlockNum) external view returns (bytes32);
+    }
+
+    interface IPostMintHook {
+        function onZkMint(address to, uint256 tokenId, uint256 nullifier) external;
+    }
+
+    interface IERC20 {
+        function transferFrom(address from, address to, uint256 amount) external returns (bool);
+        function transfer(address to, uint256 amount) external returns (bool);
+    }
+
+    interface IERC721Receiver {
+        function onERC721Received(address operator, address from, uint256 tokenId, bytes calldata data) external returns (bytes4);
+    }
+
+    interface IERC1271 {
+        function isValidSignature(bytes32 hash, bytes calldata signature) external view returns (bytes4 magicValue);
     }
 }
 
+// ERC165 interface ids
+const ERC165_INTERFACE_ID: [u8; 4] = [0x01, 0xff, 0xc9, 0xa7];
+const ERC721_INTERFACE_ID: [u8; 4] = [0x80, 0xac, 0x58, 0xcd];
+const ERC721_METADATA_INTERFACE_ID: [u8; 4] = [0x5b, 0x5e, 0x13, 0x9f];
+const INVALID_INTERFACE_ID: [u8; 4] = [0xff, 0xff, 0xff, 0xff];
+
+// bytes4(keccak256("onERC721Received(address,address,uint256,bytes)"))
+const ERC721_RECEIVED_MAGIC_VALUE: [u8; 4] = [0x15, 0x0b, 0x7a, 0x02];
+
+// bytes4(keccak256("isValidSignature(bytes32,bytes)"))
+const EIP1271_MAGIC_VALUE: [u8; 4] = [0x16, 0x26, 0xba, 0x7e];
+
+//============================================================================
+// EVENTS
+//============================================================================
+
+sol! {
+    event FeeRefunded(address indexed to, uint256 amount);
+    event MinRequiredBalanceUpdated(uint256 oldBalance, uint256 newBalance);
+    event MinRequiredBalanceScheduled(uint256 newBalance, uint256 effectiveAt);
+    event OwnershipTransferStarted(address indexed previousOwner, address indexed newOwner);
+    event OwnershipTransferred(address indexed previousOwner, address indexed newOwner);
+    event Transfer(address indexed from, address indexed to, uint256 indexed tokenId);
+    event Approval(address indexed owner, address indexed approved, uint256 indexed tokenId);
+    event ApprovalForAll(address indexed owner, address indexed operator, bool approved);
+    event CcipPaused(bool paused);
+    event MintingPaused(bool paused);
+    event CcipSendFailed(uint256 indexed tokenId, uint256 nullifier);
+    event Decommissioned();
+    event VerifyingKeyFrozen();
+    event VerifyingKeyUnregistered(uint256 indexed circuitId);
+    event NullifierUsed(uint256 indexed nullifier, address indexed recipient, uint256 tokenId);
+    event NullifierInvalidated(uint256 indexed nullifier);
+    event NullifierCleared(uint256 indexed nullifier);
+    event ProofRejected(address indexed submitter, uint8 reason);
+    event AdminActionApproved(bytes32 indexed action, address indexed admin, uint256 approvals);
+}
+
+//============================================================================
+// ERRORS
+//============================================================================
+
+// ABI-encoded custom errors for the mint and verification paths, so clients can match a
+// stable selector instead of a message. Other paths still revert with a plain message.
+sol! {
+    error ZeroRecipient();
+    error InvalidPublicInputCount(uint256 expected, uint256 actual);
+    error ZeroNullifier();
+    error UserHashMismatch(uint256 expected, uint256 actual);
+    error InvalidThreshold(uint256 threshold);
+    error LiveBalanceTooLow(uint256 balance, uint256 threshold);
+    error NullifierAlreadyUsed(uint256 nullifier);
+    error OracleCommitmentExpired(uint256 commitment);
+    error ProofExpired(uint256 age);
+    error ProofFromFuture(uint256 timestamp);
+    error InsufficientMintFee(uint256 required, uint256 paid);
+    error MintPaused();
+    error VerifierNotInitialized();
+    error InvalidProof();
+    error PublicInputNotInField(uint256 input);
+    error ProofPointAtInfinity();
+    error ProofPointNotInSubgroup();
+}
+
 //============================================================================
 // PRECOMPILE BACKEND FOR BN254 OPERATIONS (Renegade style)
 //============================================================================
 
+const EC_RECOVER_PRECOMPILE: u8 = 0x01;
 const EC_ADD_PRECOMPILE: u8 = 0x06;
 const EC_MUL_PRECOMPILE: u8 = 0x07;
 const EC_PAIRING_PRECOMPILE: u8 = 0x08;
 
+// Arbitrum system precompile (L2 block numbers and hashes)
+const ARB_SYS_PRECOMPILE: u8 = 0x64;
+
+// arbBlockHash only serves the most recent 256 L2 blocks
+const BLOCK_HASH_LOOKBACK: u64 = 256;
+
+// BN254 base field modulus p (coordinates of G1/G2 points live in this field)
+const BN254_FIELD_MODULUS: U256 = U256::from_limbs([
+    0x3c208c16d87cfd47, 0x97816a916871ca8d, 0xb85045b68181585d, 0x30644e72e131a029,
+]);
+
+// BN254 scalar field order r (public inputs and scalars live in this field)
+const BN254_SCALAR_MODULUS: U256 = U256::from_limbs([
+    0x43e1f593f0000001, 0x2833e84879b97091, 0xb85045b68181585d, 0x30644e72e131a029,
+]);
+
 /// The BN254 arithmetic backend that calls EVM precompiles
 pub struct PrecompileBackend;
 
@@ -70,8 +184,23 @@ impl PrecompileBackend {
         Ok(point)
     }
     
-    /// Call ecMul using EVM precompile for G1 scalar multiplication
+    /// Reduce a big-endian scalar modulo the group order r
+    pub fn reduce_scalar(scalar: &Scalar) -> Scalar {
+        (U256::from_be_bytes(*scalar) % BN254_SCALAR_MODULUS).to_be_bytes::<32>()
+    }
+
+    /// `ec_mul` for callers that treat a scalar >= r as a bug rather than reducing it
+    pub fn ec_mul_checked(host: &dyn stylus_sdk::prelude::Host, scalar: &Scalar, point: &G1Point) -> Result<G1Point, Vec<u8>> {
+        if U256::from_be_bytes(*scalar) >= BN254_SCALAR_MODULUS {
+            return Err("Scalar exceeds group order".as_bytes().to_vec());
+        }
+        Self::ec_mul(host, scalar, point)
+    }
+
+    /// Call ecMul using EVM precompile for G1 scalar multiplication.
+    /// The scalar is reduced mod r first, so a multiple of r takes the zero shortcut.
     pub fn ec_mul(host: &dyn stylus_sdk::prelude::Host, scalar: &Scalar, point: &G1Point) -> Result<G1Point, Vec<u8>> {
+        let scalar = &Self::reduce_scalar(scalar);
         if Self::is_scalar_zero(scalar) || Self::is_g1_zero(point) {
             return Ok([0u8; 64]); // Zero point
         }
@@ -96,6 +225,153 @@ impl PrecompileBackend {
     }
     
     
+    /// Multi-scalar multiplication sum(scalar_i * point_i) via ecMul/ecAdd.
+    /// Zero scalars and zero points contribute nothing and cost no precompile call.
+    pub fn msm(host: &dyn stylus_sdk::prelude::Host, pairs: &[(Scalar, G1Point)]) -> Result<G1Point, Vec<u8>> {
+        let mut acc = [0u8; 64];
+        for (scalar, point) in pairs {
+            if Self::is_scalar_zero(scalar) || Self::is_g1_zero(point) {
+                continue;
+            }
+            let term = Self::ec_mul(host, scalar, point)?;
+            acc = Self::ec_add(host, &acc, &term)?;
+        }
+        Ok(acc)
+    }
+
+    /// Recover a G1 point from its x coordinate and the parity of y.
+    /// Solves y^2 = x^3 + 3 (mod p); since p = 3 (mod 4) the root is (x^3 + 3)^((p + 1) / 4).
+    pub fn decompress_g1(x: &[u8; 32], y_is_odd: bool) -> Result<G1Point, &'static str> {
+        let p = BN254_FIELD_MODULUS;
+        let x_val = U256::from_be_bytes(*x);
+        if x_val >= p {
+            return Err("x coordinate not in field");
+        }
+
+        let rhs = x_val.mul_mod(x_val, p).mul_mod(x_val, p).add_mod(U256::from(3), p);
+        let y = fp_sqrt(rhs).ok_or("x coordinate not on curve")?;
+
+        // Pick the root with the requested parity
+        let y = if y.bit(0) == y_is_odd {
+            y
+        } else if y.is_zero() {
+            return Err("x coordinate not on curve");
+        } else {
+            p - y
+        };
+
+        let mut point = [0u8; 64];
+        point[0..32].copy_from_slice(x);
+        point[32..64].copy_from_slice(&y.to_be_bytes::<32>());
+        Ok(point)
+    }
+
+    /// Check a G1 point satisfies y^2 = x^3 + 3 (mod p) with both coordinates below p.
+    /// The all-zero encoding (point at infinity) is accepted, as the precompiles do.
+    pub fn is_on_curve_g1(point: &G1Point) -> bool {
+        if Self::is_g1_zero(point) {
+            return true;
+        }
+        let p = BN254_FIELD_MODULUS;
+        let x = U256::from_be_slice(&point[0..32]);
+        let y = U256::from_be_slice(&point[32..64]);
+        if x >= p || y >= p {
+            return false;
+        }
+
+        let lhs = y.mul_mod(y, p);
+        let rhs = x.mul_mod(x, p).mul_mod(x, p).add_mod(U256::from(3), p);
+        lhs == rhs
+    }
+
+    /// Check a G2 point (x_c1, x_c0, y_c1, y_c0) satisfies y^2 = x^3 + b' over Fp2 with every
+    /// limb below p. Like the G1 check, the all-zero encoding is accepted as infinity.
+    pub fn is_on_curve_g2(point: &G2Point) -> bool {
+        if point.iter().all(|&b| b == 0) {
+            return true;
+        }
+        let p = BN254_FIELD_MODULUS;
+        let limb = |i: usize| U256::from_be_slice(&point[i * 32..(i + 1) * 32]);
+        let (x1, x0, y1, y0) = (limb(0), limb(1), limb(2), limb(3));
+        if [x0, x1, y0, y1].iter().any(|l| *l >= p) {
+            return false;
+        }
+
+        let x = (x0, x1);
+        let y = (y0, y1);
+        let x3 = fp2_mul(fp2_mul(x, x), x);
+        let rhs = (x3.0.add_mod(BN254_TWIST_B.0, p), x3.1.add_mod(BN254_TWIST_B.1, p));
+        fp2_mul(y, y) == rhs
+    }
+
+    /// Check a G2 point lies in the order-r subgroup. EIP-197 requires the pairing precompile to
+    /// reject G2 inputs off the twist or outside that subgroup, so e(G1, Q) * e(-G1, Q) == 1
+    /// succeeds exactly for valid Q (G1 = generator). Costs one 2-pair pairing, ~113k gas.
+    pub fn is_in_g2_subgroup(host: &dyn stylus_sdk::prelude::Host, point: &G2Point) -> Result<bool, Vec<u8>> {
+        let generator = Self::g1_generator();
+        let neg_generator = Self::negate_g1_point(&generator);
+
+        // A rejected input is the answer "not in the subgroup", not an error
+        Self::pairing_check_or_reject(host, &[(generator, *point), (neg_generator, *point)])
+    }
+
+    /// Raw ecPairing output for the product of e(g1, g2) over `pairs` (192 bytes of calldata each)
+    pub fn pairing(host: &dyn stylus_sdk::prelude::Host, pairs: &[(G1Point, G2Point)]) -> Result<Vec<u8>, Vec<u8>> {
+        let mut calldata = Vec::with_capacity(pairs.len() * 192);
+        for (g1, g2) in pairs {
+            calldata.extend_from_slice(g1);
+            calldata.extend_from_slice(g2);
+        }
+
+        unsafe {
+            RawCall::new(host).call(Address::with_last_byte(EC_PAIRING_PRECOMPILE), &calldata)
+        }.map_err(|_| "Pairing precompile failed".as_bytes().to_vec())
+    }
+
+    /// Whether the product of e(g1, g2) over `pairs` is one (the empty product is).
+    /// Only a 32-byte word of 0 or 1 is an answer; any other output is an error, so a
+    /// misbehaving precompile cannot pass for a failed verification.
+    pub fn pairing_check(host: &dyn stylus_sdk::prelude::Host, pairs: &[(G1Point, G2Point)]) -> Result<bool, Vec<u8>> {
+        Self::parse_pairing_output(&Self::pairing(host, pairs)?)
+    }
+
+    /// pairing_check, except that the precompile rejecting an input (a point off the curve or
+    /// outside the subgroup) answers false; unexpected output is still an error
+    pub fn pairing_check_or_reject(host: &dyn stylus_sdk::prelude::Host, pairs: &[(G1Point, G2Point)]) -> Result<bool, Vec<u8>> {
+        match Self::pairing(host, pairs) {
+            Ok(result) => Self::parse_pairing_output(&result),
+            Err(_) => Ok(false),
+        }
+    }
+
+    fn parse_pairing_output(result: &[u8]) -> Result<bool, Vec<u8>> {
+        if result.len() != 32 {
+            return Err("Unexpected pairing precompile output length".as_bytes().to_vec());
+        }
+        match U256::from_be_slice(result) {
+            word if word == U256::from(1) => Ok(true),
+            word if word.is_zero() => Ok(false),
+            _ => Err("Unexpected pairing precompile output".as_bytes().to_vec()),
+        }
+    }
+
+    /// Compare two G1 points by their encoding; the precompiles only ever return canonical
+    /// coordinates, with infinity encoded as all zeroes
+    pub fn g1_eq(a: &G1Point, b: &G1Point) -> bool {
+        if Self::is_g1_zero(a) || Self::is_g1_zero(b) {
+            return Self::is_g1_zero(a) && Self::is_g1_zero(b);
+        }
+        a == b
+    }
+
+    /// The standard G1 generator (1, 2)
+    fn g1_generator() -> G1Point {
+        let mut generator = [0u8; 64];
+        generator[31] = 1;
+        generator[63] = 2;
+        generator
+    }
+
     /// Check if G1 point is zero (point at infinity)
     fn is_g1_zero(point: &G1Point) -> bool {
         point.iter().all(|&b| b == 0)
@@ -144,14 +420,156 @@ impl PrecompileBackend {
 }
 
 
-#[derive(Debug, Clone)]
+//============================================================================
+// BN254 FIELD ARITHMETIC FOR COMPRESSED POINTS
+//============================================================================
+
+// Twist coefficient b' = 3 / (9 + u) of the G2 curve y^2 = x^3 + b' over Fp2, as (c0, c1)
+const BN254_TWIST_B: (U256, U256) = (
+    U256::from_limbs([0x3267e6dc24a138e5, 0xb5b4c5e559dbefa3, 0x81be18991be06ac3, 0x2b149d40ceb8aaae]),
+    U256::from_limbs([0xe4a2bd0685c315d2, 0xa74fa084e52d1852, 0xcd2cafadeed8fdf4, 0x009713b03af0fed4]),
+);
+
+// arkworks compressed-point flags, stored in the top bits of the last (little-endian) byte
+const COMPRESSED_INFINITY_FLAG: u8 = 0x40;
+const COMPRESSED_Y_NEGATIVE_FLAG: u8 = 0x80;
+
+type Fp2 = (U256, U256);
+
+fn fp_sub(a: U256, b: U256) -> U256 {
+    let p = BN254_FIELD_MODULUS;
+    a.add_mod(p - b, p)
+}
+
+fn fp_inv(a: U256) -> U256 {
+    let p = BN254_FIELD_MODULUS;
+    a.pow_mod(p - U256::from(2), p)
+}
+
+/// Square root in Fp; p = 3 (mod 4) so a candidate is a^((p + 1) / 4)
+fn fp_sqrt(a: U256) -> Option<U256> {
+    let p = BN254_FIELD_MODULUS;
+    let root = a.pow_mod((p + U256::from(1)) >> 2, p);
+    (root.mul_mod(root, p) == a).then_some(root)
+}
+
+fn fp2_mul(a: Fp2, b: Fp2) -> Fp2 {
+    let p = BN254_FIELD_MODULUS;
+    (
+        fp_sub(a.0.mul_mod(b.0, p), a.1.mul_mod(b.1, p)),
+        a.0.mul_mod(b.1, p).add_mod(a.1.mul_mod(b.0, p), p),
+    )
+}
+
+fn fp2_neg(a: Fp2) -> Fp2 {
+    (fp_sub(U256::ZERO, a.0), fp_sub(U256::ZERO, a.1))
+}
+
+/// Square root in Fp2 = Fp[u]/(u^2 + 1) via the norm: with n = sqrt(a0^2 + a1^2),
+/// x0 = sqrt((a0 +- n) / 2) and x1 = a1 / (2 * x0)
+fn fp2_sqrt(a: Fp2) -> Option<Fp2> {
+    let p = BN254_FIELD_MODULUS;
+    let (a0, a1) = a;
+    if a1.is_zero() {
+        if let Some(root) = fp_sqrt(a0) {
+            return Some((root, U256::ZERO));
+        }
+        return fp_sqrt(fp_sub(U256::ZERO, a0)).map(|root| (U256::ZERO, root));
+    }
+
+    let n = fp_sqrt(a0.mul_mod(a0, p).add_mod(a1.mul_mod(a1, p), p))?;
+    let half = fp_inv(U256::from(2));
+    let x0 = fp_sqrt(a0.add_mod(n, p).mul_mod(half, p))
+        .or_else(|| fp_sqrt(fp_sub(a0, n).mul_mod(half, p)))?;
+    let x1 = a1.mul_mod(fp_inv(x0.add_mod(x0, p)), p);
+    Some((x0, x1))
+}
+
+/// Read a little-endian field element with the flag bits cleared; returns it with the flags
+fn read_compressed_fp(bytes: &[u8]) -> Result<(U256, u8), &'static str> {
+    let mut le = [0u8; 32];
+    le.copy_from_slice(bytes);
+    let flags = le[31] & (COMPRESSED_INFINITY_FLAG | COMPRESSED_Y_NEGATIVE_FLAG);
+    le[31] &= !flags;
+    let value = U256::from_le_bytes(le);
+    if value >= BN254_FIELD_MODULUS {
+        return Err("Compressed coordinate not in field");
+    }
+    Ok((value, flags))
+}
+
+/// Decompress an arkworks-encoded G1 point (32 bytes, little-endian x plus flags)
+fn decompress_g1_arkworks(bytes: &[u8]) -> Result<G1Point, &'static str> {
+    let (x, flags) = read_compressed_fp(bytes)?;
+    if flags & COMPRESSED_INFINITY_FLAG != 0 {
+        return Ok([0u8; 64]);
+    }
+
+    // Same curve equation as the precompile-style decompression; only the root choice differs
+    let mut point = PrecompileBackend::decompress_g1(&x.to_be_bytes::<32>(), false)
+        .map_err(|_| "Compressed G1 point not on curve")?;
+    let y = U256::from_be_slice(&point[32..64]);
+    let neg_y = fp_sub(U256::ZERO, y);
+    // The flag marks the lexicographically larger of the two roots
+    let y = if (flags & COMPRESSED_Y_NEGATIVE_FLAG != 0) == (y > neg_y) { y } else { neg_y };
+    point[32..64].copy_from_slice(&y.to_be_bytes::<32>());
+    Ok(point)
+}
+
+/// Decompress an arkworks-encoded G2 point (64 bytes: x.c0 then x.c1, little-endian,
+/// flags on x.c1) into the precompile layout (x_c1, x_c0, y_c1, y_c0)
+fn decompress_g2_arkworks(bytes: &[u8]) -> Result<G2Point, &'static str> {
+    let (x0, _) = read_compressed_fp(&bytes[0..32])?;
+    let (x1, flags) = read_compressed_fp(&bytes[32..64])?;
+    if flags & COMPRESSED_INFINITY_FLAG != 0 {
+        return Ok([0u8; 128]);
+    }
+
+    let x = (x0, x1);
+    let x3 = fp2_mul(fp2_mul(x, x), x);
+    let p = BN254_FIELD_MODULUS;
+    let rhs = (x3.0.add_mod(BN254_TWIST_B.0, p), x3.1.add_mod(BN254_TWIST_B.1, p));
+    let y = fp2_sqrt(rhs).ok_or("Compressed G2 point not on curve")?;
+    let neg_y = fp2_neg(y);
+    // Fp2 elements order by c1 first, then c0 (arkworks convention)
+    let y_is_larger = (y.1, y.0) > (neg_y.1, neg_y.0);
+    let y = if (flags & COMPRESSED_Y_NEGATIVE_FLAG != 0) == y_is_larger { y } else { neg_y };
+
+    let mut point = [0u8; 128];
+    point[0..32].copy_from_slice(&x1.to_be_bytes::<32>());
+    point[32..64].copy_from_slice(&x0.to_be_bytes::<32>());
+    point[64..96].copy_from_slice(&y.1.to_be_bytes::<32>());
+    point[96..128].copy_from_slice(&y.0.to_be_bytes::<32>());
+    Ok(point)
+}
+
+
+/// G1 point from snarkjs affine [x, y]
+fn g1_from_snarkjs(point: [U256; 2]) -> G1Point {
+    let mut out = [0u8; 64];
+    out[0..32].copy_from_slice(&point[0].to_be_bytes::<32>());
+    out[32..64].copy_from_slice(&point[1].to_be_bytes::<32>());
+    out
+}
+
+/// G2 point from snarkjs [[x_c0, x_c1], [y_c0, y_c1]] into the precompile's (x_c1, x_c0, y_c1, y_c0)
+fn g2_from_snarkjs(point: [[U256; 2]; 2]) -> G2Point {
+    let mut out = [0u8; 128];
+    out[0..32].copy_from_slice(&point[0][1].to_be_bytes::<32>());
+    out[32..64].copy_from_slice(&point[0][0].to_be_bytes::<32>());
+    out[64..96].copy_from_slice(&point[1][1].to_be_bytes::<32>());
+    out[96..128].copy_from_slice(&point[1][0].to_be_bytes::<32>());
+    out
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ZKProof {
     pub a: G1Point,
     pub b: G2Point,
     pub c: G1Point,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct VerifyingKey {
     pub alpha_g1: G1Point,
     pub beta_g2: G2Point,
@@ -161,7 +579,10 @@ pub struct VerifyingKey {
 }
 
 impl ZKProof {
-    pub fn deserialize(data: &[u8]) -> Result<Self, &'static str> {
+    /// Parse the 256-byte A | B | C layout. B is expected in the precompile's limb order
+    /// (x_c1, x_c0, y_c1, y_c0) unless `library_g2_order` is set, in which case it is read as
+    /// (x_c0, x_c1, y_c0, y_c1) and reordered.
+    pub fn deserialize(data: &[u8], library_g2_order: bool) -> Result<Self, &'static str> {
         if data.len() != 256 {
             return Err("Invalid proof length");
         }
@@ -169,17 +590,51 @@ impl ZKProof {
         // Parse G1 point A (64 bytes)
         let mut a = [0u8; 64];
         a.copy_from_slice(&data[0..64]);
+        if !PrecompileBackend::is_on_curve_g1(&a) {
+            return Err("Proof point A not on curve");
+        }
         
         // Parse G2 point B (128 bytes)
         let mut b = [0u8; 128];
         b.copy_from_slice(&data[64..192]);
+        if library_g2_order {
+            for coordinate in b.chunks_exact_mut(64) {
+                let (c0, c1) = coordinate.split_at_mut(32);
+                c0.swap_with_slice(c1);
+            }
+        }
         
         // Parse G1 point C (64 bytes)
         let mut c = [0u8; 64];
         c.copy_from_slice(&data[192..256]);
+        if !PrecompileBackend::is_on_curve_g1(&c) {
+            return Err("Proof point C not on curve");
+        }
         
         Ok(ZKProof { a, b, c })
     }
+
+    /// Parse a 128-byte arkworks compressed proof: A (32) | B (64) | C (32), each point
+    /// little-endian x with the y choice and infinity encoded in the top bits of its last byte
+    pub fn deserialize_compressed(data: &[u8]) -> Result<Self, &'static str> {
+        if data.len() != 128 {
+            return Err("Invalid compressed proof length");
+        }
+
+        let a = decompress_g1_arkworks(&data[0..32])?;
+        let b = decompress_g2_arkworks(&data[32..96])?;
+        let c = decompress_g1_arkworks(&data[96..128])?;
+        Ok(ZKProof { a, b, c })
+    }
+
+    /// Inverse of `deserialize` with precompile G2 order: a (64) | b (128) | c (64)
+    pub fn serialize(&self) -> [u8; 256] {
+        let mut data = [0u8; 256];
+        data[0..64].copy_from_slice(&self.a);
+        data[64..192].copy_from_slice(&self.b);
+        data[192..256].copy_from_slice(&self.c);
+        data
+    }
 }
 
 impl VerifyingKey {
@@ -243,6 +698,42 @@ impl VerifyingKey {
         })
     }
 
+    /// `deserialize` plus on-curve checks for every point, naming the first bad one.
+    /// Too costly for the mint path; meant for keys being installed or registered.
+    pub fn deserialize_checked(data: &[u8]) -> Result<Self, String> {
+        let vk = Self::deserialize(data).map_err(String::from)?;
+        if !PrecompileBackend::is_on_curve_g1(&vk.alpha_g1) {
+            return Err("alpha_g1 not on curve".into());
+        }
+        for (name, point) in [("beta_g2", &vk.beta_g2), ("gamma_g2", &vk.gamma_g2), ("delta_g2", &vk.delta_g2)] {
+            if !PrecompileBackend::is_on_curve_g2(point) {
+                return Err(alloc::format!("{} not on curve", name));
+            }
+        }
+        if let Some(i) = vk.gamma_abc_g1.iter().position(|point| !PrecompileBackend::is_on_curve_g1(point)) {
+            return Err(alloc::format!("gamma_abc[{}] not on curve", i));
+        }
+        Ok(vk)
+    }
+
+    /// Inverse of `deserialize`: alpha | beta | gamma | delta | u32 BE length | gamma_abc
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(452 + self.gamma_abc_g1.len() * 64);
+        data.extend_from_slice(&self.alpha_g1);
+        data.extend_from_slice(&self.beta_g2);
+        data.extend_from_slice(&self.gamma_g2);
+        data.extend_from_slice(&self.delta_g2);
+        data.extend_from_slice(&(self.gamma_abc_g1.len() as u32).to_be_bytes());
+        for point in self.gamma_abc_g1.iter() {
+            data.extend_from_slice(point);
+        }
+        data
+    }
+
+    /// keccak256 over the same byte layout `deserialize` accepts
+    pub fn fingerprint(&self) -> FixedBytes<32> {
+        keccak256(self.serialize())
+    }
 }
 
 //============================================================================
@@ -251,14 +742,80 @@ impl VerifyingKey {
 
 // Maximum age for proof timestamps (in seconds)
 // Proofs older than this will be rejected
-// Default: 300 seconds (5 minutes)
-const MAX_PROOF_AGE: u64 = 300;
+// Default: 300 seconds (5 minutes), adjustable by the owner up to one day
+const DEFAULT_MAX_PROOF_AGE: u64 = 300;
+const MAX_PROOF_AGE_LIMIT: u64 = 86400;
+
+// Balances in proofs and thresholds are scaled by 10^6; native balances are in wei (10^18)
+const WEI_PER_BALANCE_UNIT: u64 = 1_000_000_000_000;
+
+// Public inputs of the base circuit layout: nullifier + 5 (see check_public_inputs)
+const EXPECTED_PUBLIC_INPUTS: usize = 6;
+
+// Number of daily mint counters kept in the throughput ring
+const MINT_METRICS_DAYS: u64 = 30;
+
+// Upper bound on entries returned by owners_page
+const MAX_OWNERS_PAGE: u64 = 100;
+
+// Upper bound on registered VKs tried by identify_circuit
+const MAX_IDENTIFY_VKS: usize = 8;
+
+// Bit positions in feature_flags(); append new features, never renumber
+const FEATURE_CCIP_ENABLED: usize = 0; // CCIP notification sent on mint (not paused)
+const FEATURE_CCIP_NATIVE_FEE: usize = 1;
+const FEATURE_CCIP_INCLUDE_TOKEN_ID: usize = 2;
+const FEATURE_REQUIRE_AUTHORIZER: usize = 3;
+const FEATURE_SIGNED_ORACLE_COMMITMENT: usize = 4;
+const FEATURE_WALLET_CONSENT: usize = 5;
+const FEATURE_POSEIDON_INPUTS: usize = 6;
+const FEATURE_POSEIDON_USER_HASH: usize = 7;
+const FEATURE_BITMAP_NULLIFIERS: usize = 8;
+const FEATURE_LIVE_BALANCE: usize = 9;
+const FEATURE_BLOCK_HASH: usize = 10;
+const FEATURE_BATCH_TIMESTAMP_SPREAD: usize = 11;
+const FEATURE_POST_MINT_HOOK: usize = 12;
+const FEATURE_ADMIN_APPROVALS: usize = 13;
+const FEATURE_VK_FROZEN: usize = 14;
+const FEATURE_DECOMMISSIONED: usize = 15;
+const FEATURE_G2_LIBRARY_ORDER: usize = 16;
+const FEATURE_STRICT_SUBGROUP_CHECKS: usize = 17;
+const FEATURE_MINTING_PAUSED: usize = 18;
+const FEATURE_CCIP_REQUIRED: usize = 19;
+
+// Fee modes. Native is 0 so mint_fee keeps applying on existing deployments.
+const FEE_MODE_NATIVE: u8 = 0;
+const FEE_MODE_NONE: u8 = 1;
+const FEE_MODE_ERC20: u8 = 2;
+
+// CCIP configuration a fresh deployment starts with (Arbitrum Sepolia -> Ethereum Sepolia)
+const DEFAULT_CCIP_SENDER: Address = Address::new([
+    0xc3, 0x6f, 0x3c, 0x1f, 0xe8, 0xa0, 0x99, 0xe7, 0x5e, 0x9a,
+    0x86, 0x44, 0x11, 0x45, 0x17, 0x0c, 0x6d, 0x59, 0x23, 0xe5
+]); // 0xC36F3c1Fe8A099e75E9a86441145170C6d5923e5
+const DEFAULT_CCIP_RECEIVER: Address = Address::new([
+    0x2f, 0x58, 0x45, 0xc1, 0x5f, 0xfd, 0x51, 0x91, 0x70, 0x3b,
+    0x92, 0xb6, 0x8c, 0xbf, 0xc0, 0x7e, 0x3c, 0xd9, 0x50, 0x5e
+]); // 0x2f5845C15FFd5191703B92b68CbFC07e3cD9505e
+const DEFAULT_CCIP_DESTINATION_CHAIN_SELECTOR: u64 = 16015286601757825753; // Ethereum Sepolia
+
+// ProofRejected reason codes
+const REJECT_EXPIRED: u8 = 1;
+const REJECT_FUTURE: u8 = 2;
+const REJECT_REPLAYED: u8 = 3;
+const REJECT_INVALID: u8 = 4;
 
 sol_storage! {
     #[entrypoint]
     pub struct ZKMintContract {
         address owner;
+        // Nominated by transfer_ownership, becomes owner once it calls accept_ownership
+        address pending_owner;
         uint256 next_token_id;
+        // Tokens currently in existence: +1 per mint, -1 per burn
+        uint256 total_supply;
+        // Tokens destroyed so far; only ever increases (minted = supply + burned)
+        uint256 burned_count;
         mapping(uint256 => address) token_owners;
         mapping(address => uint256) token_balances;
         mapping(uint256 => address) token_approvals;
@@ -266,20 +823,213 @@ sol_storage! {
         
         // Nullifier tracking to prevent replay attacks
         mapping(uint256 => bool) used_nullifiers;
+        // Bitmap mode (fixed at deploy): 256 nullifier flags per word, keyed by nullifier / 256.
+        // Much cheaper for small sequential nullifiers; the mapping suits large/random ones.
+        bool bitmap_nullifiers;
+        mapping(uint256 => uint256) nullifier_bitmap;
         
         // Required minimum balance for minting (scaled by 10^6)
         // Example: 10 ETH = 10_000_000 (10 * 10^6)
         uint256 min_required_balance;
+
+        // Additional balance tiers accepted besides min_required_balance (same scaling).
+        // Enumerable set: positions are 1-based indexes into the list, 0 = not present
+        uint256[] valid_threshold_list;
+        mapping(uint256 => uint256) valid_threshold_positions;
+        // Per-token credential data taken from the proof's public inputs
+        mapping(uint256 => uint256) token_thresholds;
+        mapping(uint256 => uint256) token_nullifiers;
+        mapping(uint256 => uint256) token_timestamps;
+        mapping(uint256 => bytes32) token_commitments;
+        mapping(uint256 => bytes) token_metadata;
+
+        // ERC721 metadata; token_uri is base_uri followed by the decimal token id
+        string name;
+        string symbol;
+        string base_uri;
+
+        // Native fee (in wei) charged per mint, 0 = free
+        uint256 mint_fee;
+        // Mint fees kept and not yet withdrawn; the rest of the balance funds CCIP fees
+        uint256 collected_fees;
+
+        // Which fee applies (FEE_MODE_*); in ERC20 mode fee_amount of fee_token is
+        // pulled from the caller per mint via transferFrom
+        uint8 fee_mode;
+        address fee_token;
+        uint256 fee_amount;
+
+        // Sponsored mints: when required, mints must carry a signature from an authorized signer
+        bool require_authorizer;
+        mapping(address => bool) authorized_signers;
+
+        // Oracle commitments submitted with a valid oracle signature, mapped to their expiry
+        address oracle_signer;
+        bool require_signed_oracle_commitment;
+        mapping(uint256 => uint256) oracle_commitment_expiry;
+
+        // One-way end-of-life flag: minting and configuration are disabled for good,
+        // views and token ownership keep working
+        bool decommissioned;
+
+        // ERC165 interface ids advertised by supports_interface (ERC165 itself is always on)
+        mapping(bytes4 => bool) supported_interfaces;
+
+        // Contract wallet recipients must explicitly consent via EIP-1271 (EOAs are unaffected)
+        bool require_wallet_consent;
+
+        // Emergency switch for the CCIP path only, toggled by the owner or the guardian
+        address guardian;
+        bool ccip_paused;
+
+        // Emergency stop for minting; views and transfers stay available
+        bool paused;
+
+        // Circuit exposes Poseidon(public inputs) as its single public signal
+        // (requires the `poseidon` feature and a VK for such a circuit)
+        bool poseidon_inputs_mode;
+
+        // user_address_hash (public input 3) is Poseidon(to) instead of keccak256(to) reduced mod r
+        bool poseidon_user_hash;
+
+        // Only mints proving at least this threshold are bridged (0 = every mint)
+        uint256 ccip_min_tier;
+
+        // Append the minted token id to the CCIP message payload
+        bool ccip_include_token_id;
+
+        // Pay the CCIP fee in native token, quoted via getFee and funded from the contract balance
+        bool ccip_native_fee;
+
+        // CCIP sender contract called on mint, and where it delivers the message
+        address ccip_sender;
+        address ccip_receiver;
+        uint64 ccip_destination_chain_selector;
+
+        // A failed CCIP send reverts the mint; when false the mint goes through and
+        // CcipSendFailed is emitted instead
+        bool ccip_required;
+
+        // Hash of the trusted setup ceremony transcript the active VK came from.
+        // Informational only: lets users check the VK against the public ceremony record.
+        bytes32 setup_ceremony_hash;
+
+        // VK history: fingerprint of each VK and the block it became active.
+        // Appended only on rotation, so entries are sorted by block.
+        uint256[] vk_history_blocks;
+        bytes32[] vk_history_fingerprints;
+
+        // m-of-n approval for dangerous actions (decommission, VK rotation).
+        // Disabled while admin_threshold is 0. Approvals are keyed by (action, nonce)
+        // and the action's own nonce is bumped when it executes, so approvals are
+        // single use without touching those collected for other actions.
+        mapping(address => bool) admins;
+        uint256 admin_count;
+        uint256 admin_threshold;
+        mapping(bytes32 => uint256) admin_action_nonces;
+        mapping(bytes32 => mapping(address => bool)) action_approvals;
+        mapping(bytes32 => uint256) action_approval_counts;
+
+        // Ring of per-day mint counters, slot = day % MINT_METRICS_DAYS;
+        // daily_mint_slot_day records which day a slot currently counts
+        mapping(uint256 => uint256) daily_mint_counts;
+        mapping(uint256 => uint256) daily_mint_slot_day;
+
+        // Serialized VK (VerifyingKey::deserialize layout) overriding the compiled-in key;
+        // empty means get_verifying_key() is used
+        bytes verifying_key_bytes;
+
+        // One-way lock: once set the verifying key can never be replaced
+        bool vk_frozen;
+
+        // Verifying key registry for multi-circuit deployments: circuit id -> serialized VK
+        // (VerifyingKey::deserialize layout), plus the enumerable list of registered ids
+        mapping(uint256 => bytes) verifying_keys;
+        uint256[] registered_vk_id_list;
+        mapping(uint256 => uint256) registered_vk_positions; // 1-based, 0 = not registered
+        mapping(bytes32 => uint256) vk_circuit_by_fingerprint;
+        mapping(bytes32 => bool) retired_vk_fingerprints;
+
+        // Hybrid mode: the recipient's current native balance must also meet the
+        // proven threshold at mint time (opt-in, it links the address to the balance)
+        bool require_live_balance;
+
+        // Proofs carry two extra public inputs [block_number, block_hash mod r] committing
+        // to a recent L2 block, checked against ArbSys within the 256-block lookback window
+        bool require_block_hash;
+
+        // Optional contract notified after every mint, with a gas cap (0 = all remaining gas)
+        // and a failure mode: revert the mint, or ignore the hook failing
+        address post_mint_hook;
+        uint64 hook_gas_limit;
+        bool hook_revert_on_failure;
+
+        // Proof B limbs arrive as (x_c0, x_c1, y_c0, y_c1) instead of the precompile's
+        // (x_c1, x_c0, y_c1, y_c0) and are reordered on deserialization
+        bool g2_library_order;
+
+        // Explicit G2 subgroup check on proof point B before verification (on by default,
+        // adds one pairing call; can be turned off for trusted provers)
+        bool strict_subgroup_checks;
+
+        // Gas that must remain before each batch entry; below it the batch stops early
+        // and keeps what it minted (0 = batches are all-or-nothing)
+        uint64 batch_gas_reserve;
+
+        // Max allowed spread between proof timestamps in one batch mint (0 = unchecked)
+        uint256 batch_timestamp_spread;
+
+        // Freshness window for proof timestamps (seconds)
+        uint256 max_proof_age;
+
+        // Timelock for set_min_required_balance (seconds, 0 = immediate) and the change
+        // waiting for it; effective_at 0 means nothing is pending
+        uint256 min_balance_delay;
+        uint256 pending_min_balance;
+        uint256 pending_min_balance_effective_at;
+
+        // Allows clear_nullifier; off by default since a cleared nullifier can be replayed
+        bool nullifier_clearing_enabled;
+
+        // Cap on tokens an address may hold when minting to it (0 = unlimited).
+        // Applies to current balance, so transferring or burning frees room again.
+        uint256 max_mints_per_address;
     }
 }
 
 #[public]
 impl ZKMintContract {
     #[constructor]
-    pub fn constructor(&mut self, owner: Address, min_required_balance: U256) -> Result<(), Vec<u8>> {
+    pub fn constructor(
+        &mut self,
+        owner: Address,
+        min_required_balance: U256,
+        setup_ceremony_hash: FixedBytes<32>,
+        bitmap_nullifiers: bool,
+        name: String,
+        symbol: String,
+        base_uri: String,
+    ) -> Result<(), Vec<u8>> {
         self.owner.set(owner);
+        self.name.set_str(name);
+        self.symbol.set_str(symbol);
+        self.base_uri.set_str(base_uri);
+        self.bitmap_nullifiers.set(bitmap_nullifiers);
         self.next_token_id.set(U256::from(1));
+        self.max_proof_age.set(U256::from(DEFAULT_MAX_PROOF_AGE));
         self.min_required_balance.set(min_required_balance);
+        self.setup_ceremony_hash.set(setup_ceremony_hash);
+        self.strict_subgroup_checks.set(true);
+        self.ccip_sender.set(DEFAULT_CCIP_SENDER);
+        self.ccip_receiver.set(DEFAULT_CCIP_RECEIVER);
+        self.ccip_destination_chain_selector.set(U64::from(DEFAULT_CCIP_DESTINATION_CHAIN_SELECTOR));
+        self.ccip_required.set(true);
+        // Nothing is stored yet, so this checks the compiled-in key against the default layout
+        self.check_active_vk_input_count(false, false)?;
+        self.record_vk_rotation(get_verifying_key().fingerprint());
+        self.supported_interfaces.setter(FixedBytes::from(ERC165_INTERFACE_ID)).set(true);
+        self.supported_interfaces.setter(FixedBytes::from(ERC721_INTERFACE_ID)).set(true);
+        self.supported_interfaces.setter(FixedBytes::from(ERC721_METADATA_INTERFACE_ID)).set(true);
 
         Ok(())
     }
@@ -294,124 +1044,521 @@ impl ZKMintContract {
         proof_data: Vec<u8>,
         public_inputs: Vec<U256>,
     ) -> Result<bool, Vec<u8>> {
-        // Parse the ZK proof
-        let proof = ZKProof::deserialize(&proof_data)?;
-        
-        // Convert U256 public inputs to Scalar (raw bytes)
-        let mut scalar_inputs = Vec::new();
-        for input in public_inputs.iter() {
-            let bytes: [u8; 32] = input.to_be_bytes();
-            scalar_inputs.push(bytes);
+        let vk = self.active_verifying_key();
+        self.verify_proof_with_key(&vk, &proof_data, &public_inputs)
+    }
+
+    // verify_proof against the VK registered under `circuit_id` instead of the active one
+    pub fn verify_proof_for_circuit(
+        &self,
+        circuit_id: U256,
+        proof_data: Vec<u8>,
+        public_inputs: Vec<U256>,
+    ) -> Result<bool, Vec<u8>> {
+        let vk = self.registered_vk(circuit_id)?;
+        self.verify_proof_with_key(&vk, &proof_data, &public_inputs)
+    }
+
+    // verify_proof for several proofs against the active VK in one pairing call.
+    // True only if every proof is valid; does not say which one failed.
+    pub fn verify_proof_batch(
+        &self,
+        proofs: Vec<Vec<u8>>,
+        public_inputs: Vec<Vec<U256>>,
+    ) -> Result<bool, Vec<u8>> {
+        if proofs.len() != public_inputs.len() {
+            return Err("Batch length mismatch".into());
         }
-        
-        // Use compile-time constants instead of storage reads (gas optimization)
-        let vk = get_verifying_key();
-        
-        // Perform verification
-        self.groth16_verify(&proof, &vk, &scalar_inputs)
+        let vk = self.active_verifying_key();
+        if vk.gamma_abc_g1.is_empty() {
+            return Err(VerifierNotInitialized {}.abi_encode());
+        }
+
+        let mut parsed = Vec::with_capacity(proofs.len());
+        let mut scalar_inputs = Vec::with_capacity(proofs.len());
+        for (proof_data, inputs) in proofs.iter().zip(&public_inputs) {
+            let (proof, scalars) = self.parse_checked_proof(proof_data, inputs)?;
+            parsed.push(proof);
+            scalar_inputs.push(scalars);
+        }
+        self.groth16_verify_batch(&parsed, &vk, &scalar_inputs)
+    }
+
+    // Same as verify_proof, but also returns the fingerprint of the VK the result pertains to
+    pub fn verify_proof_with_context(
+        &self,
+        proof_data: Vec<u8>,
+        public_inputs: Vec<U256>,
+    ) -> Result<(bool, FixedBytes<32>), Vec<u8>> {
+        let vk = self.active_verifying_key();
+        let valid = self.verify_proof_with_key(&vk, &proof_data, &public_inputs)?;
+        Ok((valid, vk.fingerprint()))
+    }
+
+    // Debugging aid: the raw ecPairing output for this proof, uninterpreted
+    pub fn raw_pairing_result(
+        &self,
+        proof_data: Vec<u8>,
+        public_inputs: Vec<U256>,
+    ) -> Result<Vec<u8>, Vec<u8>> {
+        let vk = self.active_verifying_key();
+        let (proof, scalar_inputs) = Self::parse_proof_and_inputs(&proof_data, &public_inputs, self.g2_library_order.get())?;
+        self.groth16_pairing_output(&proof, &vk, &scalar_inputs)
+    }
+
+    // Assemble the 256-byte proof expected by verify_proof from snarkjs proof.json components.
+    // `a` and `c` are pi_a/pi_c without the trailing 1; `b` is pi_b[0..2], each as [c0, c1].
+    // The EVM pairing precompile wants G2 coordinates imaginary-first, so each pair is swapped
+    // to [c1, c0] (same ordering as generate-proof.ts and inject-vk.js).
+    pub fn deserialize_snarkjs(&self, a: [U256; 2], b: [[U256; 2]; 2], c: [U256; 2]) -> Vec<u8> {
+        let proof = ZKProof {
+            a: g1_from_snarkjs(a),
+            b: g2_from_snarkjs(b),
+            c: g1_from_snarkjs(c),
+        };
+        proof.serialize().to_vec()
+    }
+
+    // Build the VK upload blob for set_verifying_key / register_verifying_key from snarkjs
+    // verification_key.json: vk_alpha_1, vk_beta_2, vk_gamma_2, vk_delta_2 and IC, with the
+    // trailing projective coordinates dropped and G2 pairs as [c0, c1] like in the JSON
+    pub fn serialize_verifying_key(
+        &self,
+        alpha_g1: [U256; 2],
+        beta_g2: [[U256; 2]; 2],
+        gamma_g2: [[U256; 2]; 2],
+        delta_g2: [[U256; 2]; 2],
+        gamma_abc: Vec<[U256; 2]>,
+    ) -> Vec<u8> {
+        let vk = VerifyingKey {
+            alpha_g1: g1_from_snarkjs(alpha_g1),
+            beta_g2: g2_from_snarkjs(beta_g2),
+            gamma_g2: g2_from_snarkjs(gamma_g2),
+            delta_g2: g2_from_snarkjs(delta_g2),
+            gamma_abc_g1: gamma_abc.into_iter().map(g1_from_snarkjs).collect(),
+        };
+        vk.serialize()
+    }
+
+    // Run every non-cryptographic check mint_with_zk_proof performs and return the first failure.
+    // Lets a frontend reject a bad submission before paying for the pairing verification.
+    pub fn validate_public_inputs(&self, to: Address, public_inputs: Vec<U256>) -> Result<(), Vec<u8>> {
+        self.check_public_inputs(to, &public_inputs)
+    }
+
+    // Whether mint_with_zk_proof would succeed right now for these arguments (ignoring the fee).
+    // Side-effect free and staticcall-safe, but runs the full pairing verification: not cheap.
+    pub fn is_eligible(&self, to: Address, proof_data: Vec<u8>, public_inputs: Vec<U256>) -> bool {
+        if self.decommissioned.get()
+            || self.paused.get()
+            || !self.is_initialized()
+            || self.ensure_plain_mint_allowed(to).is_err()
+            || self.check_mint_cap(to).is_err()
+        {
+            return false;
+        }
+        matches!(self.rejection_reason(to, &proof_data, &public_inputs), Ok(None))
     }
 
+    #[payable]
     pub fn mint_with_zk_proof(
         &mut self,
         to: Address,
         proof_data: Vec<u8>,
         public_inputs: Vec<U256>,
     ) -> Result<U256, Vec<u8>> {
-        // Check we have the expected number of public inputs (nullifier + 5 inputs)
-        if public_inputs.len() != 6 {
-            return Err("Invalid number of public inputs".into());
-        }
-        
-        // Extract public inputs
-        // Order: [nullifier, min_required_balance, token_contract_hash, user_address_hash, timestamp, oracle_commitment]
-        let nullifier = public_inputs[0];
-        let min_balance_from_proof = public_inputs[1];
-        let proof_timestamp = public_inputs[4];
-        
-        // CRITICAL SECURITY CHECK: Validate min_required_balance matches contract requirement
-        // This prevents users from generating proofs with arbitrary lower thresholds
-        let required_min_balance = self.min_required_balance.get();
-        if min_balance_from_proof != required_min_balance {
-            return Err("Invalid min_required_balance in proof - does not match contract requirement".into());
-        }
-        
-        // Check if nullifier has been used before (prevent replay attacks)
-        if self.used_nullifiers.get(nullifier) {
-            return Err("Nullifier already used - proof replay detected".into());
+        self.ensure_plain_mint_allowed(to)?;
+        self.mint_internal(to, proof_data, public_inputs)
+    }
+
+    // Mint to the caller. public_inputs[3] must be the caller's user hash, which
+    // check_public_inputs enforces for every recipient.
+    #[payable]
+    pub fn mint_self_with_zk_proof(
+        &mut self,
+        proof_data: Vec<u8>,
+        public_inputs: Vec<U256>,
+    ) -> Result<U256, Vec<u8>> {
+        let to = self.vm().msg_sender();
+        self.mint_with_zk_proof(to, proof_data, public_inputs)
+    }
+
+    // Same as mint_with_zk_proof, but the transaction itself must execute by `deadline`
+    // (independent of proof freshness, guards against a tx lingering in the mempool)
+    #[payable]
+    pub fn mint_with_zk_proof_deadline(
+        &mut self,
+        to: Address,
+        proof_data: Vec<u8>,
+        public_inputs: Vec<U256>,
+        deadline: U256,
+    ) -> Result<U256, Vec<u8>> {
+        if U256::from(self.vm().block_timestamp()) > deadline {
+            return Err("Transaction deadline passed".into());
         }
-        
-        // SECURITY: Verify timestamp is recent (prevent using old proofs with stale data)
-        let current_time = U256::from(self.vm().block_timestamp());
-        let max_age = U256::from(MAX_PROOF_AGE);
-        
-        // Check if proof is too old
-        if current_time > proof_timestamp {
-            let age = current_time - proof_timestamp;
-            if age > max_age {
-                return Err("Proof expired - timestamp too old".into());
-            }
-        } else {
-            // Proof timestamp is in the future - reject
-            return Err("Invalid timestamp - proof from future".into());
+        self.mint_with_zk_proof(to, proof_data, public_inputs)
+    }
+
+    // Mint against the registered VK with this fingerprint instead of the active one
+    #[payable]
+    pub fn mint_with_zk_proof_by_vk(
+        &mut self,
+        to: Address,
+        proof_data: Vec<u8>,
+        public_inputs: Vec<U256>,
+        vk_fingerprint: FixedBytes<32>,
+    ) -> Result<U256, Vec<u8>> {
+        self.ensure_plain_mint_allowed(to)?;
+        let vk = self.registered_vk_by_fingerprint(vk_fingerprint)?;
+        self.mint_internal_with_key(to, &vk, proof_data, public_inputs)
+    }
+
+    // Mint against the VK registered under `circuit_id`; mint_with_zk_proof keeps using the
+    // active key. The proof must still follow this contract's public input layout.
+    #[payable]
+    pub fn mint_with_zk_proof_for_circuit(
+        &mut self,
+        to: Address,
+        proof_data: Vec<u8>,
+        public_inputs: Vec<U256>,
+        circuit_id: U256,
+    ) -> Result<U256, Vec<u8>> {
+        self.ensure_plain_mint_allowed(to)?;
+        let vk = self.registered_vk(circuit_id)?;
+        self.mint_internal_with_key(to, &vk, proof_data, public_inputs)
+    }
+
+    // Non-reverting mint for monitoring: a rejected proof emits ProofRejected (1 expired,
+    // 2 future, 3 replayed, 4 invalid), refunds msg.value and returns token id 0.
+    // Configuration errors (fee, authorizer, decommissioned...) still revert.
+    #[payable]
+    pub fn try_mint_with_zk_proof(
+        &mut self,
+        to: Address,
+        proof_data: Vec<u8>,
+        public_inputs: Vec<U256>,
+    ) -> Result<U256, Vec<u8>> {
+        self.ensure_plain_mint_allowed(to)?;
+        self.ensure_not_decommissioned()?;
+        self.ensure_not_paused()?;
+        if !self.is_initialized() {
+            return Err(VerifierNotInitialized {}.abi_encode());
         }
-        
-        // Verify the ZK proof (pass all public signals to groth16_verify)
-        if !self.verify_proof(proof_data, public_inputs)? {
-            return Err("Invalid ZK proof".into());
+
+        let paid = self.vm().msg_value();
+        let fee = self.native_fee_per_mint();
+        if paid < fee {
+            return Err(InsufficientMintFee { required: fee, paid }.abi_encode());
         }
-        
-        // CCIP: Send message cross-chain after successful verification
-        // Hardcoded CCIP configuration
-        let ccip_sender_address = Address::from([
-            0xc3, 0x6f, 0x3c, 0x1f, 0xe8, 0xa0, 0x99, 0xe7, 0x5e, 0x9a,
-            0x86, 0x44, 0x11, 0x45, 0x17, 0x0c, 0x6d, 0x59, 0x23, 0xe5
-        ]); // 0xC36F3c1Fe8A099e75E9a86441145170C6d5923e5
-        
-        let ccip_sender = ICCIPSender::new(ccip_sender_address);
-        
-        // Parâmetros da mensagem CCIP
-        let destination_chain_selector: u64 = 16015286601757825753; // Ethereum Sepolia
-        let receiver = Address::from([
-            0x2f, 0x58, 0x45, 0xc1, 0x5f, 0xfd, 0x51, 0x91, 0x70, 0x3b,
-            0x92, 0xb6, 0x8c, 0xbf, 0xc0, 0x7e, 0x3c, 0xd9, 0x50, 0x5e
-        ]); // 0x2f5845C15FFd5191703B92b68CbFC07e3cD9505e
-        
-        let message = alloc::format!(
-            "user:0x{:x},nullifier:{},timestamp:{}",
-            to,
-            nullifier,
-            proof_timestamp
-        );
-        
-        // Chamar sendMessage no contrato CCIP Sender
-        let config = Call::new();
-        let config_typed: Call<true> = unsafe { core::mem::transmute(config) };
-        let _message_id = ccip_sender.send_message(
-            &*self.vm(),
-            config_typed,
-            destination_chain_selector,
-            receiver,
-            message
-        )?;
-        
-        // Mark nullifier as used to prevent future replay
-        self.used_nullifiers.setter(nullifier).set(true);
 
-        let token_id = self.next_token_id.get();
-        self.token_owners.setter(token_id).set(to);
-        
-        let current_balance = self.token_balances.getter(to).get();
-        self.token_balances.setter(to).set(current_balance + U256::from(1));
-        
-        self.next_token_id.set(token_id + U256::from(1));
-        
+        let sender = self.vm().msg_sender();
+        if let Some(reason) = self.rejection_reason(to, &proof_data, &public_inputs)? {
+            log(self.vm(), ProofRejected { submitter: sender, reason });
+            self.refund_fee(sender, paid)?;
+            return Ok(U256::ZERO);
+        }
+
+        self.collect_token_fee(U256::from(1))?;
+        let token_id = self.record_mint(to, &public_inputs)?;
+        self.settle_fee(sender, paid, fee)?;
         Ok(token_id)
     }
 
+    // Mint several tokens in one transaction; the fee is charged per minted entry.
+    // Atomic: any failing entry (including a nullifier repeated within the batch) reverts all.
+    // With a batch gas reserve set, the batch instead stops before an entry once gas left
+    // drops below the reserve and returns the ids minted so far; those entries are committed
+    // (their nullifiers consumed), so a relayer resubmits only the remainder.
+    #[payable]
+    pub fn mint_batch_with_zk_proofs(
+        &mut self,
+        recipients: Vec<Address>,
+        proofs: Vec<Vec<u8>>,
+        public_inputs: Vec<Vec<U256>>,
+    ) -> Result<Vec<U256>, Vec<u8>> {
+        if recipients.len() != proofs.len() || recipients.len() != public_inputs.len() {
+            return Err("Batch length mismatch".into());
+        }
+        if recipients.is_empty() {
+            return Err("Empty batch".into());
+        }
+        for to in &recipients {
+            self.ensure_plain_mint_allowed(*to)?;
+        }
+        self.check_batch_nullifiers(&public_inputs)?;
+        self.check_batch_timestamps(&public_inputs)?;
+
+        let paid = self.vm().msg_value();
+        let fee_per_mint = self.native_fee_per_mint();
+        let required = fee_per_mint * U256::from(recipients.len());
+        if paid < required {
+            return Err(InsufficientMintFee { required, paid }.abi_encode());
+        }
+
+        let gas_reserve = self.batch_gas_reserve.get().to::<u64>();
+        let vk = self.active_verifying_key();
+        let mut token_ids = Vec::with_capacity(recipients.len());
+        for ((to, proof_data), inputs) in recipients.into_iter().zip(proofs).zip(public_inputs) {
+            if gas_reserve > 0 && self.vm().evm_gas_left() < gas_reserve {
+                break;
+            }
+            token_ids.push(self.mint_token(to, &vk, proof_data, inputs)?);
+        }
+
+        let minted = U256::from(token_ids.len());
+        self.collect_token_fee(minted)?;
+        let sender = self.vm().msg_sender();
+        self.settle_fee(sender, paid, fee_per_mint * minted)?;
+        Ok(token_ids)
+    }
+
+    // One proof, bound to the caller, mints one token per recipient with its own metadata.
+    // The nullifier is consumed once for the whole bundle; the fee is charged per token.
+    #[payable]
+    pub fn mint_bundle_with_zk_proof(
+        &mut self,
+        recipients: Vec<Address>,
+        metadata: Vec<Vec<u8>>,
+        proof_data: Vec<u8>,
+        public_inputs: Vec<U256>,
+    ) -> Result<Vec<U256>, Vec<u8>> {
+        if recipients.is_empty() {
+            return Err("Bundle has no recipients".into());
+        }
+        if metadata.len() != recipients.len() {
+            return Err("Metadata length must match recipient count".into());
+        }
+        for to in &recipients {
+            self.ensure_plain_mint_allowed(*to)?;
+        }
+
+        let paid = self.vm().msg_value();
+        let mints = U256::from(recipients.len());
+        let total_fee = self.native_fee_per_mint() * mints;
+        if paid < total_fee {
+            return Err(InsufficientMintFee { required: total_fee, paid }.abi_encode());
+        }
+        self.collect_token_fee(mints)?;
+
+        let attester = self.vm().msg_sender();
+        let vk = self.active_verifying_key();
+        self.verify_mint(attester, &vk, &proof_data, &public_inputs)?;
+        self.consume_nullifier(public_inputs[0]);
+
+        let mut token_ids = Vec::with_capacity(recipients.len());
+        for (to, token_metadata) in recipients.into_iter().zip(metadata) {
+            if to == Address::ZERO {
+                return Err("Cannot mint to zero address".into());
+            }
+            let token_id = self.issue_token(to, &public_inputs)?;
+            self.token_metadata.setter(token_id).set_bytes(&token_metadata);
+            token_ids.push(token_id);
+        }
+        // One nullifier backs the whole bundle: reported against the attester and the first token
+        log(self.vm(), NullifierUsed { nullifier: public_inputs[0], recipient: attester, tokenId: token_ids[0] });
+
+        self.settle_fee(attester, paid, total_fee)?;
+        Ok(token_ids)
+    }
+
+    // Mint to a smart contract wallet that consents via EIP-1271 over keccak256(proof_data)
+    #[payable]
+    pub fn mint_with_wallet_consent(
+        &mut self,
+        to: Address,
+        proof_data: Vec<u8>,
+        public_inputs: Vec<U256>,
+        signature: Vec<u8>,
+    ) -> Result<U256, Vec<u8>> {
+        if self.require_authorizer.get() {
+            return Err("Mint requires an authorizer signature".into());
+        }
+        if self.vm().code_size(to) == 0 {
+            return Err("Wallet consent only applies to contract recipients".into());
+        }
+
+        let proof_hash = keccak256(&proof_data);
+        let wallet = IERC1271::new(to);
+        let magic = wallet.is_valid_signature(&*self.vm(), Call::new(), proof_hash, signature.into())
+            .map_err(|_| b"Wallet signature check failed".to_vec())?;
+        if magic != FixedBytes::from(EIP1271_MAGIC_VALUE) {
+            return Err("Wallet did not consent to receive the credential".into());
+        }
+        self.mint_internal(to, proof_data, public_inputs)
+    }
+
+    // Sponsored mint: an authorized signer approves (to, nullifier, keccak256(proof_data)),
+    // while the ZK proof still governs eligibility
+    #[payable]
+    pub fn mint_with_authorization(
+        &mut self,
+        to: Address,
+        proof_data: Vec<u8>,
+        public_inputs: Vec<U256>,
+        signature: Vec<u8>,
+    ) -> Result<U256, Vec<u8>> {
+        let nullifier = public_inputs.first().copied().unwrap_or_default();
+        let proof_hash = keccak256(&proof_data);
+        let authorizer = self.recover_authorizer(to, nullifier, proof_hash, signature)?;
+        if !self.authorized_signers.get(authorizer) {
+            return Err("Signature is not from an authorized signer".into());
+        }
+        if self.requires_wallet_consent(to) {
+            return Err("Contract wallet recipient requires EIP-1271 consent".into());
+        }
+        self.mint_internal(to, proof_data, public_inputs)
+    }
+
+    // Recover the signer of an EIP-191 personal_sign over
+    // keccak256(chain_id ++ contract ++ to ++ nullifier ++ proof_hash)
+    pub fn recover_authorizer(
+        &self,
+        to: Address,
+        nullifier: U256,
+        proof_hash: FixedBytes<32>,
+        signature: Vec<u8>,
+    ) -> Result<Address, Vec<u8>> {
+        let mut packed = [0u8; 84];
+        packed[0..20].copy_from_slice(to.as_slice());
+        packed[20..52].copy_from_slice(&nullifier.to_be_bytes::<32>());
+        packed[52..84].copy_from_slice(proof_hash.as_slice());
+        let digest = self.domain_digest(&packed);
+
+        self.ecrecover(Self::eth_signed_message_hash(digest), &signature)
+    }
+
+    // Register an oracle commitment signed by the oracle over
+    // keccak256(chain_id ++ contract ++ commitment ++ valid_until).
+    // Callable by the owner or the oracle itself; the signature is what makes it authoritative.
+    pub fn submit_oracle_commitment(
+        &mut self,
+        commitment: U256,
+        valid_until: U256,
+        signature: Vec<u8>,
+    ) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        let oracle = self.oracle_signer.get();
+        if caller != self.owner.get() && caller != oracle {
+            return Err("Only owner or oracle can submit_oracle_commitment".into());
+        }
+        self.ensure_not_decommissioned()?;
+        if oracle == Address::ZERO {
+            return Err("Oracle signer not configured".into());
+        }
+
+        let mut packed = [0u8; 64];
+        packed[0..32].copy_from_slice(&commitment.to_be_bytes::<32>());
+        packed[32..64].copy_from_slice(&valid_until.to_be_bytes::<32>());
+        let digest = self.domain_digest(&packed);
+        if self.ecrecover(Self::eth_signed_message_hash(digest), &signature)? != oracle {
+            return Err("Commitment not signed by oracle".into());
+        }
+
+        self.oracle_commitment_expiry.setter(commitment).set(valid_until);
+        Ok(())
+    }
+
+    // ========================================================================
+    // ERC721 APPROVALS
+    // ========================================================================
+
+    pub fn set_approval_for_all(&mut self, operator: Address, approved: bool) -> Result<(), Vec<u8>> {
+        let owner = self.vm().msg_sender();
+        self.set_operator_approval(owner, operator, approved)
+    }
+
+    pub fn is_approved_for_all(&self, owner: Address, operator: Address) -> bool {
+        self.operator_approvals.getter(owner).get(operator)
+    }
+
+    // Grant or revoke several operators for msg.sender in one transaction
+    pub fn set_approval_for_all_batch(&mut self, operators: Vec<Address>, approved: bool) -> Result<(), Vec<u8>> {
+        let owner = self.vm().msg_sender();
+        for operator in operators {
+            self.set_operator_approval(owner, operator, approved)?;
+        }
+        Ok(())
+    }
+
+    // Caller must be the owner or an operator of the owner; Address::ZERO clears the approval
+    pub fn approve(&mut self, approved: Address, token_id: U256) -> Result<(), Vec<u8>> {
+        let owner = self.owner_of(token_id)?;
+        let caller = self.vm().msg_sender();
+        if caller != owner && !self.operator_approvals.getter(owner).get(caller) {
+            return Err("Caller is not owner nor approved operator".into());
+        }
+        if approved == owner {
+            return Err("Cannot approve the current owner".into());
+        }
+
+        self.token_approvals.setter(token_id).set(approved);
+        log(self.vm(), Approval { owner, approved, tokenId: token_id });
+        Ok(())
+    }
+
+    pub fn get_approved(&self, token_id: U256) -> Result<Address, Vec<u8>> {
+        self.owner_of(token_id)?;
+        Ok(self.token_approvals.get(token_id))
+    }
+
+    // Caller must be the owner, the token's approved address or an operator of the owner
+    pub fn transfer_from(&mut self, from: Address, to: Address, token_id: U256) -> Result<(), Vec<u8>> {
+        self.transfer_token(from, to, token_id)
+    }
+
+    // transfer_from, then a contract recipient must acknowledge via onERC721Received.
+    // `data` is ABI `bytes` so the selector matches safeTransferFrom(address,address,uint256,bytes).
+    pub fn safe_transfer_from(&mut self, from: Address, to: Address, token_id: U256, data: Bytes) -> Result<(), Vec<u8>> {
+        self.safe_transfer_token(from, to, token_id, data)
+    }
+
+    // The ERC721 safeTransferFrom(address,address,uint256) overload, with empty data
+    #[selector(name = "safeTransferFrom")]
+    pub fn safe_transfer_from_without_data(&mut self, from: Address, to: Address, token_id: U256) -> Result<(), Vec<u8>> {
+        self.safe_transfer_token(from, to, token_id, Vec::new().into())
+    }
+
+    // Destroy a token. Its nullifier stays consumed, so the same proof can never re-mint it.
+    // The id is not reused and the per-token proof data is kept for history.
+    pub fn burn(&mut self, token_id: U256) -> Result<(), Vec<u8>> {
+        let owner = self.owner_of(token_id)?;
+        if !self.is_approved_or_owner(self.vm().msg_sender(), owner, token_id) {
+            return Err("Caller is not owner nor approved".into());
+        }
+
+        self.token_approvals.delete(token_id);
+        self.token_owners.delete(token_id);
+        let balance = self.token_balances.get(owner);
+        self.token_balances.setter(owner).set(balance - U256::from(1));
+        self.total_supply.set(self.total_supply.get() - U256::from(1));
+        self.burned_count.set(self.burned_count.get() + U256::from(1));
+
+        log(self.vm(), Transfer { from: owner, to: Address::ZERO, tokenId: token_id });
+        Ok(())
+    }
+
+    // Move several tokens out of `from`, token_ids[i] going to to[i]; all-or-nothing
+    pub fn transfer_batch(&mut self, from: Address, to: Vec<Address>, token_ids: Vec<U256>) -> Result<(), Vec<u8>> {
+        if to.len() != token_ids.len() {
+            return Err("Batch length mismatch".into());
+        }
+        for (recipient, token_id) in to.into_iter().zip(token_ids) {
+            self.transfer_token(from, recipient, token_id)?;
+        }
+        Ok(())
+    }
+
     // ========================================================================
     // ERC721 VIEW FUNCTIONS
     // ========================================================================
 
+    pub fn supports_interface(&self, interface_id: FixedBytes<4>) -> bool {
+        if interface_id == FixedBytes::from(INVALID_INTERFACE_ID) {
+            return false;
+        }
+        interface_id == FixedBytes::from(ERC165_INTERFACE_ID) || self.supported_interfaces.get(interface_id)
+    }
+
     pub fn balance_of(&self, owner: Address) -> U256 {
         self.token_balances.getter(owner).get()
     }
@@ -424,89 +1571,3903 @@ impl ZKMintContract {
         Ok(owner)
     }
 
+    // Up to `count` (capped at MAX_OWNERS_PAGE) (token id, owner) pairs of existing tokens,
+    // starting at `start_token_id`; burned ids are skipped
+    pub fn owners_page(&self, start_token_id: U256, count: U256) -> Vec<(U256, Address)> {
+        let limit = count.min(U256::from(MAX_OWNERS_PAGE)).to::<usize>();
+        let end = self.next_token_id.get();
+        let mut page = Vec::with_capacity(limit);
+        let mut token_id = start_token_id;
+        while page.len() < limit && token_id < end {
+            let owner = self.token_owners.get(token_id);
+            if owner != Address::ZERO {
+                page.push((token_id, owner));
+            }
+            token_id += U256::from(1);
+        }
+        page
+    }
+
+    pub fn name(&self) -> String {
+        self.name.get_string()
+    }
+
+    pub fn symbol(&self) -> String {
+        self.symbol.get_string()
+    }
+
+    // Exported as ERC721Metadata's tokenURI (the default name would be tokenUri)
+    #[selector(name = "tokenURI")]
+    pub fn token_uri(&self, token_id: U256) -> Result<String, Vec<u8>> {
+        self.owner_of(token_id)?;
+        Ok(alloc::format!("{}{}", self.base_uri.get_string(), token_id))
+    }
+
     pub fn get_next_token_id(&self) -> U256 {
         self.next_token_id.get()
     }
+
+    // Token id the next successful mint will receive. Ids are sequential, so the
+    // preview is only a hint: it moves if someone else mints first.
+    pub fn preview_next_token_id(&self) -> U256 {
+        self.next_token_id.get()
+    }
+
+    // Exact payload a mint with these values would pass to sendMessage right now
+    // (the token id part, if enabled, assumes the mint is the next one)
+    pub fn preview_ccip_message(&self, to: Address, nullifier: U256, timestamp: U256) -> Vec<u8> {
+        self.build_ccip_message(to, nullifier, timestamp, self.next_token_id.get())
+            .into_bytes()
+    }
+
+    pub fn total_supply(&self) -> U256 {
+        self.total_supply.get()
+    }
+
+    pub fn total_burned(&self) -> U256 {
+        self.burned_count.get()
+    }
     
+    // Lets front-ends detect a replay before spending gas on a reverting mint
+    pub fn is_nullifier_used(&self, nullifier: U256) -> bool {
+        self.is_nullifier_consumed(nullifier)
+    }
+
     pub fn get_max_proof_age(&self) -> U256 {
-        U256::from(MAX_PROOF_AGE)
+        self.max_proof_age.get()
     }
     
+    pub fn get_max_mints_per_address(&self) -> U256 {
+        self.max_mints_per_address.get()
+    }
+
     pub fn get_min_required_balance(&self) -> U256 {
         self.min_required_balance.get()
     }
 
-    // ========================================================================
-    // ADMIN FUNCTIONS
-    // ========================================================================
-    
-    pub fn set_min_required_balance(&mut self, new_min_balance: U256) -> Result<(), Vec<u8>> {
-        // SECURITY: Only owner can update the minimum required balance
-        let caller = self.vm().msg_sender();
-        if caller != self.owner.get() {
-            return Err("Only owner can set_min_required_balance".into());
-        }
-        
-        self.min_required_balance.set(new_min_balance);
-        Ok(())
-    }
-    
-    pub fn get_owner(&self) -> Address {
-        self.owner.get()
+    // Length of the public_inputs mint expects:
+    // [nullifier, min_required_balance, token_contract_hash, user_address_hash, timestamp,
+    //  oracle_commitment], plus [arb block number, block hash mod r] when require_block_hash is on
+    pub fn get_public_input_count(&self) -> U256 {
+        U256::from(self.expected_public_input_count())
     }
-}
 
-impl ZKMintContract {
+    // Threshold to put at public_inputs[1] so the proof passes the min-balance check
+    pub fn get_expected_min_balance(&self) -> U256 {
+        self.min_required_balance.get()
+    }
 
-    fn groth16_verify(
-        &self,
-        proof: &ZKProof,
-        vk: &VerifyingKey,
-        public_inputs: &[Scalar],
-    ) -> Result<bool, Vec<u8>> {
-        if public_inputs.len() + 1 != vk.gamma_abc_g1.len() {
-            return Err("Wrong number of public inputs".into());
-        }
+    // True if a proof against this min balance threshold would be accepted
+    pub fn is_valid_threshold(&self, threshold: U256) -> bool {
+        threshold == self.min_required_balance.get() || !self.valid_threshold_positions.get(threshold).is_zero()
+    }
 
-        // Compute vk_x = gamma_abc_g1[0] + sum(public_inputs[i] * gamma_abc_g1[i+1])
-        // snarkjs returns public signals as: [nullifier, min_required_balance, token_contract_hash, user_address_hash, timestamp, oracle_commitment]
-        // gamma_abc_g1 has: [constant, nullifier_coeff, min_req_coeff, token_coeff, user_coeff, timestamp_coeff, oracle_coeff]
-        let mut vk_x = vk.gamma_abc_g1[0];
-        
-        // Multiply each public input by its corresponding gamma_abc coefficient and add to vk_x
-        for (i, input) in public_inputs.iter().enumerate() {
-            if i + 1 < vk.gamma_abc_g1.len() {
-                let gamma_abc_term = PrecompileBackend::ec_mul(&*self.vm(), input, &vk.gamma_abc_g1[i + 1])?;
-                vk_x = PrecompileBackend::ec_add(&*self.vm(), &vk_x, &gamma_abc_term)?;
+    // All accepted thresholds: min_required_balance first, then extra tiers in insertion order
+    pub fn get_valid_thresholds(&self) -> Vec<U256> {
+        let min_required = self.min_required_balance.get();
+        let mut thresholds = Vec::with_capacity(self.valid_threshold_list.len() + 1);
+        thresholds.push(min_required);
+        for i in 0..self.valid_threshold_list.len() {
+            let threshold = self.valid_threshold_list.get(i).unwrap_or_default();
+            if threshold != min_required {
+                thresholds.push(threshold);
             }
         }
+        thresholds
+    }
 
-        // Negate some points for the pairing check
-        let neg_alpha = PrecompileBackend::negate_g1_point(&vk.alpha_g1);
-        let neg_vk_x = PrecompileBackend::negate_g1_point(&vk_x);
-        let neg_c = PrecompileBackend::negate_g1_point(&proof.c);
+    pub fn get_token_metadata(&self, token_id: U256) -> Vec<u8> {
+        self.token_metadata.getter(token_id).get_bytes()
+    }
 
-        // Perform single 4-way pairing check for Groth16
-        // Verify: e(A, B) * e(-alpha, beta) * e(-vk_x, gamma) * e(-C, delta) = 1
-        let mut calldata = [0u8; 768]; // 4 pairs * 192 bytes each
-        
-        // Serialize all 4 pairs for the pairing precompile
-        calldata[0..64].copy_from_slice(&proof.a);
-        calldata[64..192].copy_from_slice(&proof.b);
-        calldata[192..256].copy_from_slice(&neg_alpha);
-        calldata[256..384].copy_from_slice(&vk.beta_g2);
-        calldata[384..448].copy_from_slice(&neg_vk_x);
-        calldata[448..576].copy_from_slice(&vk.gamma_g2);
-        calldata[576..640].copy_from_slice(&neg_c);
-        calldata[640..768].copy_from_slice(&vk.delta_g2);
-        
-        // Call EVM pairing precompile with all 4 pairs
-        let result = unsafe {
-            RawCall::new(self.vm())
-                .call(Address::with_last_byte(EC_PAIRING_PRECOMPILE), &calldata)
-        }.map_err(|_| b"Pairing precompile failed".to_vec())?;
-        
-        // Result is 32 bytes, return true if last byte is 1
-        Ok(result.len() == 32 && result[31] == 1)
+    pub fn get_token_threshold(&self, token_id: U256) -> Result<U256, Vec<u8>> {
+        self.owner_of(token_id)?;
+        Ok(self.token_thresholds.get(token_id))
+    }
+
+    // Everything stored on-chain about a credential: (owner, proof timestamp, nullifier, oracle commitment)
+    pub fn get_token_record(&self, token_id: U256) -> Result<(Address, U256, U256, FixedBytes<32>), Vec<u8>> {
+        let owner = self.owner_of(token_id)?;
+        Ok((
+            owner,
+            self.token_timestamps.get(token_id),
+            self.token_nullifiers.get(token_id),
+            self.token_commitments.get(token_id),
+        ))
+    }
+
+    pub fn get_bitmap_nullifiers(&self) -> bool {
+        self.bitmap_nullifiers.get()
+    }
+
+    pub fn get_mint_fee(&self) -> U256 {
+        self.mint_fee.get()
+    }
+
+    pub fn is_authorized_signer(&self, signer: Address) -> bool {
+        self.authorized_signers.get(signer)
+    }
+
+    pub fn get_require_authorizer(&self) -> bool {
+        self.require_authorizer.get()
+    }
+
+    pub fn get_oracle_signer(&self) -> Address {
+        self.oracle_signer.get()
+    }
+
+    pub fn get_require_signed_oracle_commitment(&self) -> bool {
+        self.require_signed_oracle_commitment.get()
+    }
+
+    pub fn get_oracle_commitment_expiry(&self, commitment: U256) -> U256 {
+        self.oracle_commitment_expiry.get(commitment)
+    }
+
+    pub fn get_require_wallet_consent(&self) -> bool {
+        self.require_wallet_consent.get()
+    }
+
+    pub fn get_guardian(&self) -> Address {
+        self.guardian.get()
+    }
+
+    pub fn is_ccip_paused(&self) -> bool {
+        self.ccip_paused.get()
+    }
+
+    pub fn get_poseidon_inputs_mode(&self) -> bool {
+        self.poseidon_inputs_mode.get()
+    }
+
+    pub fn get_poseidon_user_hash(&self) -> bool {
+        self.poseidon_user_hash.get()
     }
-}
\ No newline at end of file
+
+    // The user_address_hash a proof must carry to mint to `to`, as the circuit computes it:
+    // keccak256 of the 20 address bytes reduced mod r, or Poseidon(to) in Poseidon mode
+    pub fn expected_user_hash(&self, to: Address) -> Result<U256, Vec<u8>> {
+        if self.poseidon_user_hash.get() {
+            #[cfg(feature = "poseidon")]
+            {
+                return Ok(poseidon::hash(&[U256::from_be_slice(to.as_slice())])?);
+            }
+            #[cfg(not(feature = "poseidon"))]
+            {
+                return Err("Poseidon support not compiled in".into());
+            }
+        }
+        // A public input is a field element, so the circuit only ever sees the hash mod r
+        Ok(U256::from_be_bytes(keccak256(to).0) % BN254_SCALAR_MODULUS)
+    }
+
+    pub fn get_ccip_native_fee(&self) -> bool {
+        self.ccip_native_fee.get()
+    }
+
+    pub fn get_ccip_include_token_id(&self) -> bool {
+        self.ccip_include_token_id.get()
+    }
+
+    pub fn get_ccip_min_tier(&self) -> U256 {
+        self.ccip_min_tier.get()
+    }
+
+    pub fn is_ccip_required(&self) -> bool {
+        self.ccip_required.get()
+    }
+
+    // (sender, receiver, destination chain selector) used for the mint notification
+    pub fn get_ccip_config(&self) -> (Address, Address, u64) {
+        (
+            self.ccip_sender.get(),
+            self.ccip_receiver.get(),
+            self.ccip_destination_chain_selector.get().to::<u64>(),
+        )
+    }
+
+    pub fn get_ceremony_hash(&self) -> FixedBytes<32> {
+        self.setup_ceremony_hash.get()
+    }
+
+    // Fingerprint of the VK that was active at `block`, for auditing historical mints
+    pub fn vk_fingerprint_at(&self, block: u64) -> Result<FixedBytes<32>, Vec<u8>> {
+        let block = U256::from(block);
+        let len = self.vk_history_blocks.len();
+
+        // Binary search for the last rotation at or before `block`
+        let (mut lo, mut hi) = (0usize, len);
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if self.vk_history_blocks.get(mid).unwrap_or_default() <= block {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+
+        if lo == 0 {
+            return Err("No verifying key active at that block".into());
+        }
+        Ok(self.vk_history_fingerprints.get(lo - 1).unwrap_or_default())
+    }
+
+    // False while the contract still runs with the placeholder VK from verifying_key_stub.rs
+    pub fn is_initialized(&self) -> bool {
+        !self.active_verifying_key().gamma_abc_g1.is_empty()
+    }
+
+    // Canonicalize a compactly stored G1 point: returns the 64-byte (x, y) encoding
+    pub fn decompress_g1(&self, x: FixedBytes<32>, y_is_odd: bool) -> Result<Vec<u8>, Vec<u8>> {
+        let point = PrecompileBackend::decompress_g1(&x.0, y_is_odd)?;
+        Ok(point.to_vec())
+    }
+
+    // Hash of this contract's deployed code, for comparison against a published audited build
+    pub fn self_code_hash(&self) -> FixedBytes<32> {
+        self.vm().code_hash(self.vm().contract_address())
+    }
+
+    // Whether the stored VK (if any) is the compiled-in one, to catch drift between the
+    // key setup.sh generated and the one uploaded later. True when nothing is stored.
+    pub fn vk_matches_compiled(&self) -> bool {
+        match self.stored_verifying_key() {
+            Some(stored) => stored.fingerprint() == get_verifying_key().fingerprint(),
+            None => true,
+        }
+    }
+
+    // Number of public inputs the active VK expects (gamma_abc_g1 has one extra constant term)
+    pub fn vk_public_input_count(&self) -> U256 {
+        let len = self.active_verifying_key().gamma_abc_g1.len();
+        U256::from(len.saturating_sub(1))
+    }
+
+    pub fn get_vk_gamma_abc_len(&self) -> U256 {
+        U256::from(self.active_verifying_key().gamma_abc_g1.len())
+    }
+
+    // gamma_abc_g1[index] of the active VK, for comparing the deployment against the ceremony output
+    pub fn get_vk_gamma_abc(&self, index: U256) -> Result<[u8; 64], Vec<u8>> {
+        let vk = self.active_verifying_key();
+        usize::try_from(index)
+            .ok()
+            .and_then(|i| vk.gamma_abc_g1.get(i).copied())
+            .ok_or_else(|| b"gamma_abc index out of range".to_vec())
+    }
+
+    // Diagnose a G2 encoding/generator-convention mismatch: checks e(G1, beta_g2) == e(G1, expected)
+    // for the standard G1 generator, i.e. that `expected` (128 bytes, precompile layout) is the VK's
+    // beta_g2. False if they differ or the precompile rejects either point as malformed.
+    pub fn assert_g2_generator(&self, expected: Vec<u8>) -> Result<bool, Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can assert_g2_generator".into());
+        }
+        if expected.len() != 128 {
+            return Err("Expected G2 point must be 128 bytes".into());
+        }
+
+        let vk = self.active_verifying_key();
+        let generator = PrecompileBackend::g1_generator();
+        let neg_generator = PrecompileBackend::negate_g1_point(&generator);
+        let mut expected_g2 = [0u8; 128];
+        expected_g2.copy_from_slice(&expected);
+
+        PrecompileBackend::pairing_check_or_reject(
+            &*self.vm(),
+            &[(generator, vk.beta_g2), (neg_generator, expected_g2)],
+        )
+    }
+
+    // VK-only sanity check, run before any proof is submitted. Groth16 imposes no relation
+    // between alpha, beta, gamma and delta, so this checks well-formedness:
+    //  - alpha and every gamma_abc point are on the curve, alpha is not the identity;
+    //  - e(alpha, Q) * e(-alpha, Q) == 1 for Q in {beta, gamma, delta}, which the precompile
+    //    only evaluates if each Q is on the twist and in the order-r subgroup;
+    //  - e(alpha, beta) != 1, i.e. the pairing the verifier relies on is non-degenerate.
+    pub fn vk_well_formed(&self) -> Result<bool, Vec<u8>> {
+        let vk = self.active_verifying_key();
+        if vk.gamma_abc_g1.is_empty() {
+            return Err(VerifierNotInitialized {}.abi_encode());
+        }
+        if PrecompileBackend::is_g1_zero(&vk.alpha_g1)
+            || !PrecompileBackend::is_on_curve_g1(&vk.alpha_g1)
+            || !vk.gamma_abc_g1.iter().all(PrecompileBackend::is_on_curve_g1)
+        {
+            return Ok(false);
+        }
+
+        let neg_alpha = PrecompileBackend::negate_g1_point(&vk.alpha_g1);
+        let mut pairs = Vec::with_capacity(6);
+        for q in [vk.beta_g2, vk.gamma_g2, vk.delta_g2] {
+            pairs.push((vk.alpha_g1, q));
+            pairs.push((neg_alpha, q));
+        }
+        if !PrecompileBackend::pairing_check_or_reject(&*self.vm(), &pairs)? {
+            return Ok(false);
+        }
+
+        let degenerate = PrecompileBackend::pairing_check_or_reject(&*self.vm(), &[(vk.alpha_g1, vk.beta_g2)])?;
+        Ok(!degenerate)
+    }
+
+    // Precompile addresses used for BN254 arithmetic: (ecAdd, ecMul, ecPairing)
+    pub fn get_precompile_addresses(&self) -> (Address, Address, Address) {
+        (
+            Address::with_last_byte(EC_ADD_PRECOMPILE),
+            Address::with_last_byte(EC_MUL_PRECOMPILE),
+            Address::with_last_byte(EC_PAIRING_PRECOMPILE),
+        )
+    }
+
+    // ========================================================================
+    // ADMIN FUNCTIONS
+    // ========================================================================
+    
+    pub fn set_min_required_balance(&mut self, new_min_balance: U256) -> Result<(), Vec<u8>> {
+        // SECURITY: Only owner can update the minimum required balance
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_min_required_balance".into());
+        }
+        self.ensure_not_decommissioned()?;
+        
+        // A change invalidates proofs in flight, so with a delay set it is only announced here
+        let delay = self.min_balance_delay.get();
+        if delay.is_zero() {
+            self.pending_min_balance_effective_at.set(U256::ZERO);
+            self.update_min_required_balance(new_min_balance);
+            return Ok(());
+        }
+        let effective_at = U256::from(self.vm().block_timestamp()) + delay;
+        self.pending_min_balance.set(new_min_balance);
+        self.pending_min_balance_effective_at.set(effective_at);
+        log(self.vm(), MinRequiredBalanceScheduled { newBalance: new_min_balance, effectiveAt: effective_at });
+        Ok(())
+    }
+
+    // Anyone can apply a scheduled min_required_balance once its delay has passed
+    pub fn apply_pending_min_balance(&mut self) -> Result<(), Vec<u8>> {
+        self.ensure_not_decommissioned()?;
+        let effective_at = self.pending_min_balance_effective_at.get();
+        if effective_at.is_zero() {
+            return Err("No pending min_required_balance change".into());
+        }
+        if U256::from(self.vm().block_timestamp()) < effective_at {
+            return Err("Min balance timelock not expired".into());
+        }
+
+        self.pending_min_balance_effective_at.set(U256::ZERO);
+        self.update_min_required_balance(self.pending_min_balance.get());
+        Ok(())
+    }
+
+    pub fn set_min_balance_delay(&mut self, seconds: U256) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_min_balance_delay".into());
+        }
+        self.ensure_not_decommissioned()?;
+
+        self.min_balance_delay.set(seconds);
+        Ok(())
+    }
+
+    pub fn get_min_balance_delay(&self) -> U256 {
+        self.min_balance_delay.get()
+    }
+
+    // (new balance, effective_at) of the scheduled change; effective_at 0 if none
+    pub fn get_pending_min_balance(&self) -> (U256, U256) {
+        let effective_at = self.pending_min_balance_effective_at.get();
+        if effective_at.is_zero() {
+            return (U256::ZERO, U256::ZERO);
+        }
+        (self.pending_min_balance.get(), effective_at)
+    }
+
+    pub fn set_authorized_signer(&mut self, signer: Address, authorized: bool) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_authorized_signer".into());
+        }
+        self.ensure_not_decommissioned()?;
+
+        self.authorized_signers.setter(signer).set(authorized);
+        Ok(())
+    }
+
+    pub fn set_require_authorizer(&mut self, required: bool) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_require_authorizer".into());
+        }
+        self.ensure_not_decommissioned()?;
+
+        self.require_authorizer.set(required);
+        Ok(())
+    }
+
+    // Advertise (or stop advertising) an extra interface without a code change
+    pub fn set_supported_interface(&mut self, interface_id: FixedBytes<4>, supported: bool) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_supported_interface".into());
+        }
+        self.ensure_not_decommissioned()?;
+        if interface_id == FixedBytes::from(INVALID_INTERFACE_ID) {
+            return Err("0xffffffff is not a valid interface id".into());
+        }
+
+        self.supported_interfaces.setter(interface_id).set(supported);
+        Ok(())
+    }
+
+    pub fn set_oracle_signer(&mut self, oracle: Address) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_oracle_signer".into());
+        }
+        self.ensure_not_decommissioned()?;
+
+        self.oracle_signer.set(oracle);
+        Ok(())
+    }
+
+    pub fn set_require_signed_oracle_commitment(&mut self, required: bool) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_require_signed_oracle_commitment".into());
+        }
+        self.ensure_not_decommissioned()?;
+
+        self.require_signed_oracle_commitment.set(required);
+        Ok(())
+    }
+
+    pub fn set_require_wallet_consent(&mut self, required: bool) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_require_wallet_consent".into());
+        }
+        self.ensure_not_decommissioned()?;
+
+        self.require_wallet_consent.set(required);
+        Ok(())
+    }
+
+    pub fn set_guardian(&mut self, guardian: Address) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_guardian".into());
+        }
+        self.ensure_not_decommissioned()?;
+
+        self.guardian.set(guardian);
+        Ok(())
+    }
+
+    // Fast-response control: the guardian can stop cross-chain sends without touching mint config
+    pub fn set_ccip_paused(&mut self, paused: bool) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() && caller != self.guardian.get() {
+            return Err("Only owner or guardian can set_ccip_paused".into());
+        }
+        self.ensure_not_decommissioned()?;
+
+        self.ccip_paused.set(paused);
+        log(self.vm(), CcipPaused { paused });
+        Ok(())
+    }
+
+    // Halts every mint path (single, batch, bundle, try_mint); views and transfers keep working
+    pub fn pause(&mut self) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can pause".into());
+        }
+        self.ensure_not_decommissioned()?;
+
+        self.paused.set(true);
+        log(self.vm(), MintingPaused { paused: true });
+        Ok(())
+    }
+
+    pub fn unpause(&mut self) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can unpause".into());
+        }
+        self.ensure_not_decommissioned()?;
+
+        self.paused.set(false);
+        log(self.vm(), MintingPaused { paused: false });
+        Ok(())
+    }
+
+    pub fn set_poseidon_inputs_mode(&mut self, enabled: bool) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_poseidon_inputs_mode".into());
+        }
+        self.ensure_not_decommissioned()?;
+        if enabled && !cfg!(feature = "poseidon") {
+            return Err("Poseidon support not compiled in".into());
+        }
+        self.check_active_vk_input_count(enabled, self.require_block_hash.get())?;
+
+        self.poseidon_inputs_mode.set(enabled);
+        Ok(())
+    }
+
+    pub fn set_poseidon_user_hash(&mut self, enabled: bool) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_poseidon_user_hash".into());
+        }
+        self.ensure_not_decommissioned()?;
+        if enabled && !cfg!(feature = "poseidon") {
+            return Err("Poseidon support not compiled in".into());
+        }
+
+        self.poseidon_user_hash.set(enabled);
+        Ok(())
+    }
+
+    pub fn set_ccip_native_fee(&mut self, enabled: bool) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_ccip_native_fee".into());
+        }
+        self.ensure_not_decommissioned()?;
+
+        self.ccip_native_fee.set(enabled);
+        Ok(())
+    }
+
+    pub fn set_ccip_include_token_id(&mut self, include: bool) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_ccip_include_token_id".into());
+        }
+        self.ensure_not_decommissioned()?;
+
+        self.ccip_include_token_id.set(include);
+        Ok(())
+    }
+
+    pub fn set_ccip_config(
+        &mut self,
+        sender: Address,
+        receiver: Address,
+        destination_chain_selector: u64,
+    ) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_ccip_config".into());
+        }
+        self.ensure_not_decommissioned()?;
+
+        if sender == Address::ZERO || receiver == Address::ZERO {
+            return Err("CCIP sender and receiver cannot be the zero address".into());
+        }
+        self.ccip_sender.set(sender);
+        self.ccip_receiver.set(receiver);
+        self.ccip_destination_chain_selector.set(U64::from(destination_chain_selector));
+        Ok(())
+    }
+
+    pub fn set_ccip_required(&mut self, required: bool) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_ccip_required".into());
+        }
+        self.ensure_not_decommissioned()?;
+
+        self.ccip_required.set(required);
+        Ok(())
+    }
+
+    pub fn set_ccip_min_tier(&mut self, min_tier: U256) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_ccip_min_tier".into());
+        }
+        self.ensure_not_decommissioned()?;
+
+        self.ccip_min_tier.set(min_tier);
+        Ok(())
+    }
+
+    pub fn set_valid_threshold(&mut self, threshold: U256, valid: bool) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_valid_threshold".into());
+        }
+        self.ensure_not_decommissioned()?;
+
+        let position = self.valid_threshold_positions.get(threshold);
+        if valid && position.is_zero() {
+            self.valid_threshold_list.push(threshold);
+            let len = U256::from(self.valid_threshold_list.len());
+            self.valid_threshold_positions.setter(threshold).set(len);
+        } else if !valid && !position.is_zero() {
+            // Swap-and-pop keeps the list gap-free
+            let index = position.to::<usize>() - 1;
+            let last_index = self.valid_threshold_list.len() - 1;
+            if index != last_index {
+                let last = self.valid_threshold_list.get(last_index).unwrap_or_default();
+                if let Some(mut slot) = self.valid_threshold_list.setter(index) {
+                    slot.set(last);
+                }
+                self.valid_threshold_positions.setter(last).set(position);
+            }
+            self.valid_threshold_list.pop();
+            self.valid_threshold_positions.delete(threshold);
+        }
+        Ok(())
+    }
+
+    pub fn set_require_live_balance(&mut self, required: bool) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_require_live_balance".into());
+        }
+        self.ensure_not_decommissioned()?;
+
+        self.require_live_balance.set(required);
+        Ok(())
+    }
+
+    pub fn get_require_live_balance(&self) -> bool {
+        self.require_live_balance.get()
+    }
+
+    // Switching this requires a VK for a circuit with the two extra block inputs
+    pub fn set_require_block_hash(&mut self, required: bool) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_require_block_hash".into());
+        }
+        self.ensure_not_decommissioned()?;
+        self.check_active_vk_input_count(self.poseidon_inputs_mode.get(), required)?;
+
+        self.require_block_hash.set(required);
+        Ok(())
+    }
+
+    pub fn get_require_block_hash(&self) -> bool {
+        self.require_block_hash.get()
+    }
+
+    // Zero address disables the hook; gas_limit 0 forwards all remaining gas
+    pub fn set_post_mint_hook(&mut self, hook: Address, gas_limit: u64, revert_on_failure: bool) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_post_mint_hook".into());
+        }
+        self.ensure_not_decommissioned()?;
+
+        self.post_mint_hook.set(hook);
+        self.hook_gas_limit.set(U64::from(gas_limit));
+        self.hook_revert_on_failure.set(revert_on_failure);
+        Ok(())
+    }
+
+    pub fn get_post_mint_hook(&self) -> (Address, u64, bool) {
+        (
+            self.post_mint_hook.get(),
+            self.hook_gas_limit.get().to::<u64>(),
+            self.hook_revert_on_failure.get(),
+        )
+    }
+
+    // Note deserialize_snarkjs already emits precompile order; leave this off for its output
+    pub fn set_g2_library_order(&mut self, enabled: bool) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_g2_library_order".into());
+        }
+        self.ensure_not_decommissioned()?;
+
+        self.g2_library_order.set(enabled);
+        Ok(())
+    }
+
+    pub fn get_g2_library_order(&self) -> bool {
+        self.g2_library_order.get()
+    }
+
+    pub fn set_strict_subgroup_checks(&mut self, enabled: bool) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_strict_subgroup_checks".into());
+        }
+        self.ensure_not_decommissioned()?;
+
+        self.strict_subgroup_checks.set(enabled);
+        Ok(())
+    }
+
+    pub fn get_strict_subgroup_checks(&self) -> bool {
+        self.strict_subgroup_checks.get()
+    }
+
+    pub fn set_batch_gas_reserve(&mut self, gas_reserve: u64) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_batch_gas_reserve".into());
+        }
+        self.ensure_not_decommissioned()?;
+
+        self.batch_gas_reserve.set(U64::from(gas_reserve));
+        Ok(())
+    }
+
+    pub fn get_batch_gas_reserve(&self) -> u64 {
+        self.batch_gas_reserve.get().to::<u64>()
+    }
+
+    // Cap on tokens an address may hold for a mint to go through; 0 disables the cap
+    pub fn set_max_mints_per_address(&mut self, cap: U256) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_max_mints_per_address".into());
+        }
+        self.ensure_not_decommissioned()?;
+
+        self.max_mints_per_address.set(cap);
+        Ok(())
+    }
+
+    pub fn set_max_proof_age(&mut self, seconds: U256) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_max_proof_age".into());
+        }
+        self.ensure_not_decommissioned()?;
+        if seconds.is_zero() || seconds > U256::from(MAX_PROOF_AGE_LIMIT) {
+            return Err("Max proof age must be between 1 second and one day".into());
+        }
+
+        self.max_proof_age.set(seconds);
+        Ok(())
+    }
+
+    // Reject batches mixing proofs from different oracle epochs; 0 disables the check
+    pub fn set_batch_timestamp_spread(&mut self, max_spread: U256) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_batch_timestamp_spread".into());
+        }
+        self.ensure_not_decommissioned()?;
+
+        self.batch_timestamp_spread.set(max_spread);
+        Ok(())
+    }
+
+    // Mints on day `day_index` (block_timestamp / 86400). Only the last
+    // MINT_METRICS_DAYS days are retained; older days read as 0.
+    pub fn daily_mint_count(&self, day_index: U256) -> U256 {
+        let slot = day_index % U256::from(MINT_METRICS_DAYS);
+        if self.daily_mint_slot_day.get(slot) != day_index {
+            return U256::ZERO;
+        }
+        self.daily_mint_counts.get(slot)
+    }
+
+    pub fn get_batch_timestamp_spread(&self) -> U256 {
+        self.batch_timestamp_spread.get()
+    }
+
+    pub fn set_base_uri(&mut self, base_uri: String) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_base_uri".into());
+        }
+        self.ensure_not_decommissioned()?;
+
+        self.base_uri.set_str(base_uri);
+        Ok(())
+    }
+
+    pub fn set_mint_fee(&mut self, new_fee: U256) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_mint_fee".into());
+        }
+        self.ensure_not_decommissioned()?;
+
+        self.mint_fee.set(new_fee);
+        Ok(())
+    }
+
+    // FEE_MODE_NATIVE (0) charges mint_fee, FEE_MODE_NONE (1) is free, FEE_MODE_ERC20 (2)
+    // charges fee_amount of fee_token
+    pub fn set_fee_mode(&mut self, mode: u8) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_fee_mode".into());
+        }
+        self.ensure_not_decommissioned()?;
+
+        if ![FEE_MODE_NATIVE, FEE_MODE_NONE, FEE_MODE_ERC20].contains(&mode) {
+            return Err("Unknown fee mode".into());
+        }
+        if mode == FEE_MODE_ERC20 && self.fee_token.get() == Address::ZERO {
+            return Err("Set the fee token before enabling ERC20 fees".into());
+        }
+        self.fee_mode.set(U8::from(mode));
+        Ok(())
+    }
+
+    pub fn set_erc20_fee(&mut self, token: Address, amount: U256) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_erc20_fee".into());
+        }
+        self.ensure_not_decommissioned()?;
+
+        if token == Address::ZERO {
+            return Err("Fee token cannot be the zero address".into());
+        }
+        self.fee_token.set(token);
+        self.fee_amount.set(amount);
+        Ok(())
+    }
+
+    // (mode, token, amount) of the fee configuration
+    pub fn get_fee_config(&self) -> (u8, Address, U256) {
+        (
+            self.fee_mode.get().to::<u8>(),
+            self.fee_token.get(),
+            self.fee_amount.get(),
+        )
+    }
+
+    // Move ERC20 tokens held by the contract, e.g. accumulated ERC20 mint fees
+    pub fn rescue_erc20(&mut self, token: Address, to: Address, amount: U256) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can rescue_erc20".into());
+        }
+
+        let config: Call<true> = unsafe { core::mem::transmute(Call::new()) };
+        let ok = IERC20::new(token).transfer(&*self.vm(), config, to, amount)
+            .map_err(|_| b"ERC20 transfer failed".to_vec())?;
+        if !ok {
+            return Err("ERC20 transfer failed".into());
+        }
+        Ok(())
+    }
+
+    // Send the collected mint fees to `to`. Only those: the rest of the balance is what
+    // pays the CCIP fee in native-fee mode.
+    pub fn withdraw_fees(&mut self, to: Address) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can withdraw_fees".into());
+        }
+
+        let amount = self.collected_fees.get();
+        if amount.is_zero() {
+            return Ok(());
+        }
+        self.collected_fees.set(U256::ZERO);
+        unsafe {
+            RawCall::new_with_value(self.vm(), amount).call(to, &[])
+        }.map_err(|_| b"Fee withdrawal failed".to_vec())?;
+        Ok(())
+    }
+
+    pub fn get_collected_fees(&self) -> U256 {
+        self.collected_fees.get()
+    }
+    
+    // Replace the active VK with `vk_bytes` (VerifyingKey::deserialize layout) from the ceremony
+    // identified by `setup_ceremony_hash`. An empty blob reverts to the compiled-in key.
+    // Needs admin approvals of set_vk_action_id when the approval flow is enabled.
+    pub fn set_verifying_key(&mut self, vk_bytes: Vec<u8>, setup_ceremony_hash: FixedBytes<32>) -> Result<(), Vec<u8>> {
+        let action = self.set_vk_action_id(vk_bytes.clone(), setup_ceremony_hash);
+        self.authorize_dangerous_action(action, "set_verifying_key")?;
+        self.ensure_not_decommissioned()?;
+        if self.vk_frozen.get() {
+            return Err("verifying key frozen".into());
+        }
+
+        let vk = if vk_bytes.is_empty() {
+            get_verifying_key()
+        } else {
+            let vk = VerifyingKey::deserialize_checked(&vk_bytes)?;
+            self.check_vk_input_count(&vk)?;
+            vk
+        };
+        self.verifying_key_bytes.set_bytes(&vk_bytes);
+        self.setup_ceremony_hash.set(setup_ceremony_hash);
+        self.record_vk_rotation(vk.fingerprint());
+        Ok(())
+    }
+
+    pub fn set_vk_action_id(&self, vk_bytes: Vec<u8>, setup_ceremony_hash: FixedBytes<32>) -> FixedBytes<32> {
+        let mut data = Vec::with_capacity(96);
+        data.extend_from_slice(keccak256(b"set_verifying_key").as_slice());
+        data.extend_from_slice(keccak256(&vk_bytes).as_slice());
+        data.extend_from_slice(setup_ceremony_hash.as_slice());
+        keccak256(data)
+    }
+
+    // Serialized active VK: the stored blob, or the compiled-in key when none is stored
+    pub fn get_verifying_key_bytes(&self) -> Vec<u8> {
+        if self.verifying_key_bytes.is_empty() {
+            return get_verifying_key().serialize();
+        }
+        self.verifying_key_bytes.get_bytes()
+    }
+
+    // Permanently commit to the current circuit; set_verifying_key reverts afterwards
+    pub fn freeze_verifying_key(&mut self) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can freeze_verifying_key".into());
+        }
+        self.ensure_not_decommissioned()?;
+
+        if self.vk_frozen.get() {
+            return Err("verifying key frozen".into());
+        }
+        self.vk_frozen.set(true);
+        log(self.vm(), VerifyingKeyFrozen {});
+        Ok(())
+    }
+
+    pub fn is_vk_frozen(&self) -> bool {
+        self.vk_frozen.get()
+    }
+
+    // Register (or replace) the VK for `circuit_id`. Counts as a VK rotation, so it needs
+    // admin approvals of register_vk_action_id when the approval flow is enabled.
+    pub fn register_verifying_key(&mut self, circuit_id: U256, vk_bytes: Vec<u8>) -> Result<(), Vec<u8>> {
+        let action = self.register_vk_action_id(circuit_id, vk_bytes.clone());
+        self.authorize_dangerous_action(action, "register_verifying_key")?;
+        self.ensure_not_decommissioned()?;
+        if self.vk_frozen.get() {
+            return Err("verifying key frozen".into());
+        }
+
+        let vk = VerifyingKey::deserialize_checked(&vk_bytes)?;
+        self.verifying_keys.setter(circuit_id).set_bytes(&vk_bytes);
+        self.vk_circuit_by_fingerprint.setter(vk.fingerprint()).set(circuit_id);
+        self.retired_vk_fingerprints.delete(vk.fingerprint());
+        if self.registered_vk_positions.get(circuit_id).is_zero() {
+            self.registered_vk_id_list.push(circuit_id);
+            let position = U256::from(self.registered_vk_id_list.len());
+            self.registered_vk_positions.setter(circuit_id).set(position);
+        }
+        Ok(())
+    }
+
+    // Retire a circuit: proofs selecting its VK fail with "circuit retired" from now on.
+    // The VK currently active by default cannot be retired until another one replaces it.
+    pub fn unregister_verifying_key(&mut self, circuit_id: U256) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can unregister_verifying_key".into());
+        }
+        self.ensure_not_decommissioned()?;
+        if self.vk_frozen.get() {
+            return Err("verifying key frozen".into());
+        }
+
+        let position = self.registered_vk_positions.get(circuit_id);
+        if position.is_zero() {
+            return Err("Circuit not registered".into());
+        }
+        let vk_bytes = self.verifying_keys.getter(circuit_id).get_bytes();
+        let fingerprint = VerifyingKey::deserialize(&vk_bytes)?.fingerprint();
+        if fingerprint == self.active_verifying_key().fingerprint() {
+            return Err("Cannot unregister the active verifying key without a replacement".into());
+        }
+
+        // Swap-and-pop keeps the id list gap-free
+        let index = position.to::<usize>() - 1;
+        let last_index = self.registered_vk_id_list.len() - 1;
+        if index != last_index {
+            let last = self.registered_vk_id_list.get(last_index).unwrap_or_default();
+            if let Some(mut slot) = self.registered_vk_id_list.setter(index) {
+                slot.set(last);
+            }
+            self.registered_vk_positions.setter(last).set(position);
+        }
+        self.registered_vk_id_list.pop();
+        self.registered_vk_positions.delete(circuit_id);
+        self.verifying_keys.delete(circuit_id);
+        self.retired_vk_fingerprints.setter(fingerprint).set(true);
+
+        log(self.vm(), VerifyingKeyUnregistered { circuitId: circuit_id });
+        Ok(())
+    }
+
+    pub fn register_vk_action_id(&self, circuit_id: U256, vk_bytes: Vec<u8>) -> FixedBytes<32> {
+        let mut data = Vec::with_capacity(96);
+        data.extend_from_slice(keccak256(b"register_verifying_key").as_slice());
+        data.extend_from_slice(&circuit_id.to_be_bytes::<32>());
+        data.extend_from_slice(keccak256(&vk_bytes).as_slice());
+        keccak256(data)
+    }
+
+    pub fn registered_vk_count(&self) -> U256 {
+        U256::from(self.registered_vk_id_list.len())
+    }
+
+    // Registered circuit ids, in no particular order (unregistering reorders the list)
+    pub fn registered_vk_ids(&self) -> Vec<U256> {
+        (0..self.registered_vk_id_list.len())
+            .filter_map(|i| self.registered_vk_id_list.get(i))
+            .collect()
+    }
+
+    // Find which registered circuit a proof was built for (tries at most MAX_IDENTIFY_VKS keys)
+    pub fn identify_circuit(&self, proof_data: Vec<u8>, public_inputs: Vec<U256>) -> Result<U256, Vec<u8>> {
+        let count = self.registered_vk_id_list.len().min(MAX_IDENTIFY_VKS);
+        for i in 0..count {
+            let circuit_id = self.registered_vk_id_list.get(i).unwrap_or_default();
+            let vk_bytes = self.verifying_keys.getter(circuit_id).get_bytes();
+            let Ok(vk) = VerifyingKey::deserialize(&vk_bytes) else {
+                continue;
+            };
+            // A key expecting a different number of inputs is simply not a match
+            if matches!(self.verify_proof_with_key(&vk, &proof_data, &public_inputs), Ok(true)) {
+                return Ok(circuit_id);
+            }
+        }
+        Err("Proof does not match any registered verifying key".into())
+    }
+
+    pub fn set_admin_action_id(&self, admin: Address, enabled: bool) -> FixedBytes<32> {
+        let mut data = [0u8; 53];
+        data[..32].copy_from_slice(keccak256(b"set_admin").as_slice());
+        data[32..52].copy_from_slice(admin.as_slice());
+        data[52] = enabled as u8;
+        keccak256(data)
+    }
+
+    // Needs admin approvals of set_admin_action_id when the approval flow is enabled.
+    pub fn set_admin(&mut self, admin: Address, enabled: bool) -> Result<(), Vec<u8>> {
+        let action = self.set_admin_action_id(admin, enabled);
+        self.authorize_dangerous_action(action, "set_admin")?;
+        self.ensure_not_decommissioned()?;
+
+        if admin == Address::ZERO {
+            return Err("Admin cannot be the zero address".into());
+        }
+        if self.admins.get(admin) == enabled {
+            return Ok(());
+        }
+        let count = self.admin_count.get();
+        if enabled {
+            self.admin_count.set(count + U256::from(1));
+        } else {
+            if count - U256::from(1) < self.admin_threshold.get() {
+                return Err("Removing admin would leave fewer admins than the threshold".into());
+            }
+            self.admin_count.set(count - U256::from(1));
+        }
+        self.admins.setter(admin).set(enabled);
+        Ok(())
+    }
+
+    // 0 disables the approval flow and leaves dangerous actions owner-only
+    pub fn set_admin_threshold_action_id(&self, threshold: U256) -> FixedBytes<32> {
+        let mut data = [0u8; 64];
+        data[..32].copy_from_slice(keccak256(b"set_admin_threshold").as_slice());
+        data[32..].copy_from_slice(&threshold.to_be_bytes::<32>());
+        keccak256(data)
+    }
+
+    // Needs admin approvals of set_admin_threshold_action_id when the approval flow is enabled.
+    pub fn set_admin_threshold(&mut self, threshold: U256) -> Result<(), Vec<u8>> {
+        let action = self.set_admin_threshold_action_id(threshold);
+        self.authorize_dangerous_action(action, "set_admin_threshold")?;
+        self.ensure_not_decommissioned()?;
+
+        if threshold > self.admin_count.get() {
+            return Err("Threshold exceeds admin count".into());
+        }
+        self.admin_threshold.set(threshold);
+        Ok(())
+    }
+
+    // Approve a pending action, identified by keccak256 of its description
+    // (see decommission_action_id). Returns the approval count so far.
+    pub fn approve_action(&mut self, action: FixedBytes<32>) -> Result<U256, Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if !self.admins.get(caller) {
+            return Err("Only admins can approve actions".into());
+        }
+        self.ensure_not_decommissioned()?;
+
+        let key = self.approval_key(action);
+        if self.action_approvals.getter(key).get(caller) {
+            return Err("Action already approved by caller".into());
+        }
+        self.action_approvals.setter(key).insert(caller, true);
+        let approvals = self.action_approval_counts.get(key) + U256::from(1);
+        self.action_approval_counts.setter(key).set(approvals);
+
+        log(self.vm(), AdminActionApproved { action, admin: caller, approvals });
+        Ok(approvals)
+    }
+
+    pub fn get_action_approvals(&self, action: FixedBytes<32>) -> U256 {
+        self.action_approval_counts.get(self.approval_key(action))
+    }
+
+    pub fn is_admin(&self, account: Address) -> bool {
+        self.admins.get(account)
+    }
+
+    pub fn get_admin_threshold(&self) -> U256 {
+        self.admin_threshold.get()
+    }
+
+    pub fn decommission_action_id(&self) -> FixedBytes<32> {
+        keccak256(b"decommission")
+    }
+
+    // Permanently retire the contract without self-destruct.
+    // Needs admin_threshold approvals when the approval flow is enabled.
+    pub fn decommission(&mut self) -> Result<(), Vec<u8>> {
+        self.authorize_dangerous_action(keccak256(b"decommission"), "decommission")?;
+        self.ensure_not_decommissioned()?;
+
+        self.decommissioned.set(true);
+        log(self.vm(), Decommissioned {});
+        Ok(())
+    }
+
+    // Burn a nullifier without minting (revocation, or one reserved off-chain by mistake).
+    // Also logs NullifierUsed so watchers see every consumption; as no token is issued,
+    // recipient is the caller and tokenId is 0 (never a real id, ids start at 1).
+    pub fn invalidate_nullifier(&mut self, nullifier: U256) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can invalidate_nullifier".into());
+        }
+        self.ensure_not_decommissioned()?;
+        if self.is_nullifier_consumed(nullifier) {
+            return Err("Nullifier already used".into());
+        }
+
+        self.consume_nullifier(nullifier);
+        log(self.vm(), NullifierUsed { nullifier, recipient: caller, tokenId: U256::ZERO });
+        log(self.vm(), NullifierInvalidated { nullifier });
+        Ok(())
+    }
+
+    pub fn set_nullifier_clearing_enabled(&mut self, enabled: bool) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can set_nullifier_clearing_enabled".into());
+        }
+        self.ensure_not_decommissioned()?;
+
+        self.nullifier_clearing_enabled.set(enabled);
+        Ok(())
+    }
+
+    pub fn is_nullifier_clearing_enabled(&self) -> bool {
+        self.nullifier_clearing_enabled.get()
+    }
+
+    pub fn clear_nullifier_action_id(&self, nullifier: U256) -> FixedBytes<32> {
+        let mut data = [0u8; 64];
+        data[..32].copy_from_slice(keccak256(b"clear_nullifier").as_slice());
+        data[32..].copy_from_slice(&nullifier.to_be_bytes::<32>());
+        keccak256(data)
+    }
+
+    // Recovery only: makes the nullifier usable again, so the proof behind it can be replayed.
+    // Requires nullifier clearing to be enabled, and admin approvals when that flow is on.
+    pub fn clear_nullifier(&mut self, nullifier: U256) -> Result<(), Vec<u8>> {
+        let action = self.clear_nullifier_action_id(nullifier);
+        self.authorize_dangerous_action(action, "clear_nullifier")?;
+        self.ensure_not_decommissioned()?;
+        if !self.nullifier_clearing_enabled.get() {
+            return Err("Nullifier clearing disabled".into());
+        }
+        if !self.is_nullifier_consumed(nullifier) {
+            return Err("Nullifier not used".into());
+        }
+
+        self.release_nullifier(nullifier);
+        log(self.vm(), NullifierCleared { nullifier });
+        Ok(())
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused.get()
+    }
+
+    pub fn is_decommissioned(&self) -> bool {
+        self.decommissioned.get()
+    }
+
+    // All optional modes in one word, one bit per FEATURE_* constant
+    pub fn feature_flags(&self) -> U256 {
+        let flags = [
+            (FEATURE_CCIP_ENABLED, !self.ccip_paused.get()),
+            (FEATURE_CCIP_NATIVE_FEE, self.ccip_native_fee.get()),
+            (FEATURE_CCIP_INCLUDE_TOKEN_ID, self.ccip_include_token_id.get()),
+            (FEATURE_REQUIRE_AUTHORIZER, self.require_authorizer.get()),
+            (FEATURE_SIGNED_ORACLE_COMMITMENT, self.require_signed_oracle_commitment.get()),
+            (FEATURE_WALLET_CONSENT, self.require_wallet_consent.get()),
+            (FEATURE_POSEIDON_INPUTS, self.poseidon_inputs_mode.get()),
+            (FEATURE_POSEIDON_USER_HASH, self.poseidon_user_hash.get()),
+            (FEATURE_BITMAP_NULLIFIERS, self.bitmap_nullifiers.get()),
+            (FEATURE_LIVE_BALANCE, self.require_live_balance.get()),
+            (FEATURE_BLOCK_HASH, self.require_block_hash.get()),
+            (FEATURE_BATCH_TIMESTAMP_SPREAD, !self.batch_timestamp_spread.get().is_zero()),
+            (FEATURE_POST_MINT_HOOK, self.post_mint_hook.get() != Address::ZERO),
+            (FEATURE_ADMIN_APPROVALS, !self.admin_threshold.get().is_zero()),
+            (FEATURE_VK_FROZEN, self.vk_frozen.get()),
+            (FEATURE_DECOMMISSIONED, self.decommissioned.get()),
+            (FEATURE_G2_LIBRARY_ORDER, self.g2_library_order.get()),
+            (FEATURE_STRICT_SUBGROUP_CHECKS, self.strict_subgroup_checks.get()),
+            (FEATURE_MINTING_PAUSED, self.paused.get()),
+            (FEATURE_CCIP_REQUIRED, self.ccip_required.get()),
+        ];
+        let mut word = U256::ZERO;
+        for (bit, enabled) in flags {
+            word.set_bit(bit, enabled);
+        }
+        word
+    }
+
+    pub fn get_owner(&self) -> Address {
+        self.owner.get()
+    }
+
+    pub fn get_pending_owner(&self) -> Address {
+        self.pending_owner.get()
+    }
+
+    // Step one of a two-step handoff; the zero address cancels a pending transfer
+    pub fn transfer_ownership(&mut self, new_owner: Address) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.owner.get() {
+            return Err("Only owner can transfer_ownership".into());
+        }
+
+        self.pending_owner.set(new_owner);
+        log(self.vm(), OwnershipTransferStarted { previousOwner: caller, newOwner: new_owner });
+        Ok(())
+    }
+
+    pub fn accept_ownership(&mut self) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        if caller != self.pending_owner.get() || caller == Address::ZERO {
+            return Err("Only pending owner can accept_ownership".into());
+        }
+
+        let previous_owner = self.owner.get();
+        self.owner.set(caller);
+        self.pending_owner.set(Address::ZERO);
+        log(self.vm(), OwnershipTransferred { previousOwner: previous_owner, newOwner: caller });
+        Ok(())
+    }
+}
+
+impl ZKMintContract {
+
+    fn mint_internal(
+        &mut self,
+        to: Address,
+        proof_data: Vec<u8>,
+        public_inputs: Vec<U256>,
+    ) -> Result<U256, Vec<u8>> {
+        let vk = self.active_verifying_key();
+        self.mint_internal_with_key(to, &vk, proof_data, public_inputs)
+    }
+
+    fn mint_internal_with_key(
+        &mut self,
+        to: Address,
+        vk: &VerifyingKey,
+        proof_data: Vec<u8>,
+        public_inputs: Vec<U256>,
+    ) -> Result<U256, Vec<u8>> {
+        // Check the attached fee covers the configured mint fee
+        let paid = self.vm().msg_value();
+        let fee = self.native_fee_per_mint();
+        if paid < fee {
+            return Err(InsufficientMintFee { required: fee, paid }.abi_encode());
+        }
+        self.collect_token_fee(U256::from(1))?;
+
+        let token_id = self.mint_token(to, vk, proof_data, public_inputs)?;
+
+        // Only the fee is kept, anything paid above it goes back to the caller
+        let sender = self.vm().msg_sender();
+        self.settle_fee(sender, paid, fee)?;
+
+        Ok(token_id)
+    }
+
+    // Verify one proof and mint its token. Fee handling is left to the caller.
+    fn mint_token(
+        &mut self,
+        to: Address,
+        vk: &VerifyingKey,
+        proof_data: Vec<u8>,
+        public_inputs: Vec<U256>,
+    ) -> Result<U256, Vec<u8>> {
+        self.verify_mint(to, vk, &proof_data, &public_inputs)?;
+        self.record_mint(to, &public_inputs)
+    }
+
+    // Every check a mint performs before recording it, including the pairing verification
+    fn verify_mint(
+        &self,
+        to: Address,
+        vk: &VerifyingKey,
+        proof_data: &[u8],
+        public_inputs: &[U256],
+    ) -> Result<(), Vec<u8>> {
+        self.ensure_not_decommissioned()?;
+        self.ensure_not_paused()?;
+
+        // Fail early on the common "forgot to run setup.sh" deployment
+        if vk.gamma_abc_g1.is_empty() {
+            return Err(VerifierNotInitialized {}.abi_encode());
+        }
+
+        // Catch clients that put everything into proof_data and left the inputs empty
+        if public_inputs.is_empty() {
+            return Err(InvalidPublicInputCount {
+                expected: U256::from(self.expected_public_input_count()),
+                actual: U256::ZERO,
+            }.abi_encode());
+        }
+
+        // Cheap consistency checks before the expensive pairing verification
+        self.check_public_inputs(to, public_inputs)?;
+
+        // Verify the ZK proof (pass all public signals to groth16_verify)
+        let circuit_inputs = self.circuit_public_inputs(public_inputs)?;
+        if !self.verify_proof_with_key(vk, proof_data, &circuit_inputs)? {
+            return Err(InvalidProof {}.abi_encode());
+        }
+        Ok(())
+    }
+
+    // Consume the nullifier and issue the token for already checked and verified public inputs
+    fn record_mint(&mut self, to: Address, public_inputs: &[U256]) -> Result<U256, Vec<u8>> {
+        // Mark nullifier as used to prevent future replay
+        self.consume_nullifier(public_inputs[0]);
+        let token_id = self.issue_token(to, public_inputs)?;
+        log(self.vm(), NullifierUsed { nullifier: public_inputs[0], recipient: to, tokenId: token_id });
+        Ok(token_id)
+    }
+
+    // Issue one token backed by `public_inputs`; the caller handles the nullifier
+    fn issue_token(&mut self, to: Address, public_inputs: &[U256]) -> Result<U256, Vec<u8>> {
+        // Extract public inputs
+        // Order: [nullifier, min_required_balance, token_contract_hash, user_address_hash, timestamp, oracle_commitment]
+        let nullifier = public_inputs[0];
+        let min_balance_from_proof = public_inputs[1];
+        let proof_timestamp = public_inputs[4];
+        let oracle_commitment = public_inputs[5];
+
+        self.check_mint_cap(to)?;
+
+        // Token id is assigned up front so the CCIP message can reference it
+        let token_id = self.next_token_id.get();
+        let next_token_id = token_id
+            .checked_add(U256::from(1))
+            .ok_or_else(|| b"token id space exhausted".to_vec())?;
+
+        // CCIP: Send message cross-chain after successful verification
+        // While the bridge is paused minting continues without the cross-chain notification,
+        // and mints below the configured tier skip it to save bridge fees
+        if !self.ccip_paused.get() && min_balance_from_proof >= self.ccip_min_tier.get() {
+            let message = self.build_ccip_message(to, nullifier, proof_timestamp, token_id);
+            match self.send_ccip_message(message) {
+                Ok(_) => {}
+                Err(err) if self.ccip_required.get() => return Err(err),
+                // A router outage must not block minting once the proof has been verified
+                Err(_) => log(self.vm(), CcipSendFailed { tokenId: token_id, nullifier }),
+            }
+        }
+
+        self.token_owners.setter(token_id).set(to);
+        self.token_thresholds.setter(token_id).set(min_balance_from_proof);
+        self.token_nullifiers.setter(token_id).set(nullifier);
+        self.token_timestamps.setter(token_id).set(proof_timestamp);
+        self.token_commitments.setter(token_id).set(FixedBytes::from(oracle_commitment.to_be_bytes::<32>()));
+        
+        let current_balance = self.token_balances.getter(to).get();
+        self.token_balances.setter(to).set(current_balance + U256::from(1));
+        
+        self.next_token_id.set(next_token_id);
+        self.total_supply.set(self.total_supply.get() + U256::from(1));
+        log(self.vm(), Transfer { from: Address::ZERO, to, tokenId: token_id });
+        self.record_daily_mint();
+        self.call_post_mint_hook(to, token_id, nullifier)?;
+
+        Ok(token_id)
+    }
+
+    // Classify why a submission would be rejected, or None if it can be minted
+    fn rejection_reason(&self, to: Address, proof_data: &[u8], public_inputs: &[U256]) -> Result<Option<u8>, Vec<u8>> {
+        if public_inputs.len() != self.expected_public_input_count() {
+            return Ok(Some(REJECT_INVALID));
+        }
+        if self.is_nullifier_consumed(public_inputs[0]) {
+            return Ok(Some(REJECT_REPLAYED));
+        }
+
+        let current_time = U256::from(self.vm().block_timestamp());
+        let proof_timestamp = public_inputs[4];
+        if proof_timestamp >= current_time {
+            return Ok(Some(REJECT_FUTURE));
+        }
+        if current_time - proof_timestamp > self.max_proof_age.get() {
+            return Ok(Some(REJECT_EXPIRED));
+        }
+
+        if self.check_public_inputs(to, public_inputs).is_err() {
+            return Ok(Some(REJECT_INVALID));
+        }
+        let circuit_inputs = self.circuit_public_inputs(public_inputs)?;
+        if !matches!(self.verify_proof(proof_data.to_vec(), circuit_inputs), Ok(true)) {
+            return Ok(Some(REJECT_INVALID));
+        }
+        Ok(None)
+    }
+
+    fn call_post_mint_hook(&mut self, to: Address, token_id: U256, nullifier: U256) -> Result<(), Vec<u8>> {
+        let hook = self.post_mint_hook.get();
+        if hook == Address::ZERO {
+            return Ok(());
+        }
+
+        let mut config = Call::new();
+        let gas_limit = self.hook_gas_limit.get().to::<u64>();
+        if gas_limit > 0 {
+            config = config.gas(gas_limit);
+        }
+        let config_typed: Call<true> = unsafe { core::mem::transmute(config) };
+        let result = IPostMintHook::new(hook).on_zk_mint(&*self.vm(), config_typed, to, token_id, nullifier);
+        if result.is_err() && self.hook_revert_on_failure.get() {
+            return Err("Post-mint hook failed".into());
+        }
+        Ok(())
+    }
+
+    // VK registered under circuit_id
+    fn registered_vk(&self, circuit_id: U256) -> Result<VerifyingKey, Vec<u8>> {
+        if self.registered_vk_positions.get(circuit_id).is_zero() {
+            return Err("Unknown circuit id".into());
+        }
+        let vk_bytes = self.verifying_keys.getter(circuit_id).get_bytes();
+        Ok(VerifyingKey::deserialize(&vk_bytes)?)
+    }
+
+    // Registered VK whose fingerprint matches. The index may point at a circuit whose key
+    // was replaced since, so the stored key is re-fingerprinted before use.
+    fn registered_vk_by_fingerprint(&self, fingerprint: FixedBytes<32>) -> Result<VerifyingKey, Vec<u8>> {
+        if self.retired_vk_fingerprints.get(fingerprint) {
+            return Err("circuit retired".into());
+        }
+        let circuit_id = self.vk_circuit_by_fingerprint.get(fingerprint);
+        let vk_bytes = self.verifying_keys.getter(circuit_id).get_bytes();
+        match VerifyingKey::deserialize(&vk_bytes) {
+            Ok(vk) if vk.fingerprint() == fingerprint => Ok(vk),
+            _ => Err("No registered verifying key matches fingerprint".into()),
+        }
+    }
+
+    fn native_fee_per_mint(&self) -> U256 {
+        if self.fee_mode.get().to::<u8>() == FEE_MODE_NATIVE {
+            self.mint_fee.get()
+        } else {
+            U256::ZERO
+        }
+    }
+
+    // Pull the ERC20 fee for `mints` mints from the caller; no-op outside ERC20 mode
+    fn collect_token_fee(&mut self, mints: U256) -> Result<(), Vec<u8>> {
+        if self.fee_mode.get().to::<u8>() != FEE_MODE_ERC20 {
+            return Ok(());
+        }
+        let amount = self.fee_amount.get() * mints;
+        if amount.is_zero() {
+            return Ok(());
+        }
+
+        let sender = self.vm().msg_sender();
+        let this = self.vm().contract_address();
+        let config: Call<true> = unsafe { core::mem::transmute(Call::new()) };
+        let ok = IERC20::new(self.fee_token.get()).transfer_from(&*self.vm(), config, sender, this, amount)
+            .map_err(|_| b"ERC20 fee transfer failed".to_vec())?;
+        if !ok {
+            return Err("ERC20 fee transfer failed".into());
+        }
+        Ok(())
+    }
+
+    fn record_daily_mint(&mut self) {
+        let day = U256::from(self.vm().block_timestamp() / 86400);
+        let slot = day % U256::from(MINT_METRICS_DAYS);
+        let count = if self.daily_mint_slot_day.get(slot) == day {
+            self.daily_mint_counts.get(slot)
+        } else {
+            // Slot still holds an older day: overwrite it
+            self.daily_mint_slot_day.setter(slot).set(day);
+            U256::ZERO
+        };
+        self.daily_mint_counts.setter(slot).set(count + U256::from(1));
+    }
+
+    // Mode changes must keep the active key usable
+    fn check_active_vk_input_count(&self, poseidon_inputs_mode: bool, require_block_hash: bool) -> Result<(), Vec<u8>> {
+        Self::check_vk_input_count_for(&self.active_verifying_key(), poseidon_inputs_mode, require_block_hash)
+    }
+
+    fn expected_public_input_count(&self) -> usize {
+        if self.require_block_hash.get() { EXPECTED_PUBLIC_INPUTS + 2 } else { EXPECTED_PUBLIC_INPUTS }
+    }
+
+    // A key for another circuit would only fail once someone submits a proof; catch it when
+    // it becomes the active key. Registry keys may follow other layouts and are not checked.
+    fn check_vk_input_count(&self, vk: &VerifyingKey) -> Result<(), Vec<u8>> {
+        Self::check_vk_input_count_for(vk, self.poseidon_inputs_mode.get(), self.require_block_hash.get())
+    }
+
+    // Same check for the input layout a mode change is about to select. A placeholder key
+    // (no gamma_abc points, nothing verifies) is exempt: it is how the modes get configured
+    // before setup.sh compiles in the real key, and how a deployment moves to another layout
+    // (placeholder, then the mode change, then the key for the new layout).
+    fn check_vk_input_count_for(vk: &VerifyingKey, poseidon_inputs_mode: bool, require_block_hash: bool) -> Result<(), Vec<u8>> {
+        if vk.gamma_abc_g1.is_empty() {
+            return Ok(());
+        }
+        let circuit_inputs = match (poseidon_inputs_mode, require_block_hash) {
+            (true, _) => 1,
+            (false, true) => EXPECTED_PUBLIC_INPUTS + 2,
+            (false, false) => EXPECTED_PUBLIC_INPUTS,
+        };
+        if vk.gamma_abc_g1.len() != circuit_inputs + 1 {
+            return Err("VK gamma_abc count mismatch".into());
+        }
+        Ok(())
+    }
+
+    fn check_block_hash_commitment(&self, block_number: U256, committed_hash: U256) -> Result<(), Vec<u8>> {
+        let arb_sys = IArbSys::new(Address::with_last_byte(ARB_SYS_PRECOMPILE));
+        let current_block = arb_sys.arb_block_number(&*self.vm(), Call::new())
+            .map_err(|_| b"ArbSys block number lookup failed".to_vec())?;
+        if block_number >= current_block || current_block - block_number > U256::from(BLOCK_HASH_LOOKBACK) {
+            return Err("Committed block outside the 256-block lookback window".into());
+        }
+
+        let block_hash = arb_sys.arb_block_hash(&*self.vm(), Call::new(), block_number)
+            .map_err(|_| b"ArbSys block hash lookup failed".to_vec())?;
+        if U256::from_be_bytes(block_hash.0) % BN254_SCALAR_MODULUS != committed_hash {
+            return Err("Block hash commitment mismatch".into());
+        }
+        Ok(())
+    }
+
+    // A repeated nullifier would fail its replay check anyway, but only after the pairing
+    // verification of every earlier entry; catch it before any of that gas is spent
+    fn check_batch_nullifiers(&self, public_inputs: &[Vec<U256>]) -> Result<(), Vec<u8>> {
+        let nullifiers: Vec<U256> = public_inputs.iter().filter_map(|inputs| inputs.first().copied()).collect();
+        for (i, nullifier) in nullifiers.iter().enumerate() {
+            if nullifiers[..i].contains(nullifier) {
+                return Err("Duplicate nullifier in batch".into());
+            }
+        }
+        Ok(())
+    }
+
+    fn check_batch_timestamps(&self, public_inputs: &[Vec<U256>]) -> Result<(), Vec<u8>> {
+        let max_spread = self.batch_timestamp_spread.get();
+        if max_spread.is_zero() {
+            return Ok(());
+        }
+
+        // Entries with too few inputs are skipped here and rejected by check_public_inputs
+        let mut timestamps = public_inputs.iter().filter_map(|inputs| inputs.get(4).copied());
+        let Some(first) = timestamps.next() else {
+            return Ok(());
+        };
+        let (min, max) = timestamps.fold((first, first), |(lo, hi), t| (lo.min(t), hi.max(t)));
+        if max - min > max_spread {
+            return Err("batch timestamps inconsistent".into());
+        }
+        Ok(())
+    }
+
+    fn transfer_token(&mut self, from: Address, to: Address, token_id: U256) -> Result<(), Vec<u8>> {
+        let owner = self.token_owners.get(token_id);
+        if owner == Address::ZERO {
+            return Err("Token does not exist".into());
+        }
+        if owner != from {
+            return Err("From is not the token owner".into());
+        }
+        if to == Address::ZERO {
+            return Err("Cannot transfer to zero address".into());
+        }
+
+        if !self.is_approved_or_owner(self.vm().msg_sender(), owner, token_id) {
+            return Err("Caller is not owner nor approved".into());
+        }
+
+        // Per-token approval does not survive a change of owner
+        self.token_approvals.delete(token_id);
+        let from_balance = self.token_balances.get(from);
+        self.token_balances.setter(from).set(from_balance - U256::from(1));
+        let to_balance = self.token_balances.get(to);
+        self.token_balances.setter(to).set(to_balance + U256::from(1));
+        self.token_owners.setter(token_id).set(to);
+
+        log(self.vm(), Transfer { from, to, tokenId: token_id });
+        Ok(())
+    }
+
+    fn safe_transfer_token(&mut self, from: Address, to: Address, token_id: U256, data: Bytes) -> Result<(), Vec<u8>> {
+        self.transfer_token(from, to, token_id)?;
+        if self.vm().code_size(to) == 0 {
+            return Ok(());
+        }
+
+        let operator = self.vm().msg_sender();
+        let config_typed: Call<true> = unsafe { core::mem::transmute(Call::new()) };
+        let magic = IERC721Receiver::new(to)
+            .on_erc_721_received(&*self.vm(), config_typed, operator, from, token_id, data)
+            .map_err(|_| b"Transfer to non ERC721Receiver implementer".to_vec())?;
+        if magic != FixedBytes::from(ERC721_RECEIVED_MAGIC_VALUE) {
+            return Err("Transfer to non ERC721Receiver implementer".into());
+        }
+        Ok(())
+    }
+
+    fn is_approved_or_owner(&self, caller: Address, owner: Address, token_id: U256) -> bool {
+        caller == owner
+            || self.token_approvals.get(token_id) == caller
+            || self.operator_approvals.getter(owner).get(caller)
+    }
+
+    fn set_operator_approval(&mut self, owner: Address, operator: Address, approved: bool) -> Result<(), Vec<u8>> {
+        if operator == owner {
+            return Err("Cannot approve self as operator".into());
+        }
+
+        self.operator_approvals.setter(owner).setter(operator).set(approved);
+        log(self.vm(), ApprovalForAll { owner, operator, approved });
+        Ok(())
+    }
+
+    // Stored VK when one was uploaded, otherwise the compile-time constants (no storage reads)
+    fn active_verifying_key(&self) -> VerifyingKey {
+        self.stored_verifying_key().unwrap_or_else(get_verifying_key)
+    }
+
+    fn stored_verifying_key(&self) -> Option<VerifyingKey> {
+        if self.verifying_key_bytes.is_empty() {
+            return None;
+        }
+        VerifyingKey::deserialize(&self.verifying_key_bytes.get_bytes()).ok()
+    }
+
+    fn verify_proof_with_key(
+        &self,
+        vk: &VerifyingKey,
+        proof_data: &[u8],
+        public_inputs: &[U256],
+    ) -> Result<bool, Vec<u8>> {
+        if vk.gamma_abc_g1.is_empty() {
+            return Err(VerifierNotInitialized {}.abi_encode());
+        }
+
+        let (proof, scalar_inputs) = self.parse_checked_proof(proof_data, public_inputs)?;
+        
+        // Perform verification
+        self.groth16_verify(&proof, vk, &scalar_inputs)
+    }
+
+    // parse_proof_and_inputs plus the point checks every proof must pass before pairing
+    fn parse_checked_proof(
+        &self,
+        proof_data: &[u8],
+        public_inputs: &[U256],
+    ) -> Result<(ZKProof, Vec<Scalar>), Vec<u8>> {
+        let (proof, scalar_inputs) = Self::parse_proof_and_inputs(proof_data, public_inputs, self.g2_library_order.get())?;
+
+        // Never the case for an honest proof, and the zero shortcuts in ec_add could hide it
+        if PrecompileBackend::is_g1_zero(&proof.a) || PrecompileBackend::is_g1_zero(&proof.c) {
+            return Err(ProofPointAtInfinity {}.abi_encode());
+        }
+
+        // Reject a B from a small-order cofactor subgroup before it can forge acceptance
+        if self.strict_subgroup_checks.get() && !PrecompileBackend::is_in_g2_subgroup(&*self.vm(), &proof.b)? {
+            return Err(ProofPointNotInSubgroup {}.abi_encode());
+        }
+        Ok((proof, scalar_inputs))
+    }
+
+    fn parse_proof_and_inputs(
+        proof_data: &[u8],
+        public_inputs: &[U256],
+        library_g2_order: bool,
+    ) -> Result<(ZKProof, Vec<Scalar>), Vec<u8>> {
+        // Parse the ZK proof, uncompressed (256 bytes) or compressed (128 bytes)
+        let proof = if proof_data.len() == 128 {
+            ZKProof::deserialize_compressed(proof_data)?
+        } else {
+            ZKProof::deserialize(proof_data, library_g2_order)?
+        };
+        
+        // Convert U256 public inputs to Scalar (raw bytes)
+        let mut scalar_inputs = Vec::new();
+        for input in public_inputs.iter() {
+            // ecMul would silently reduce it, letting two distinct inputs verify as one
+            if *input >= BN254_SCALAR_MODULUS {
+                return Err(PublicInputNotInField { input: *input }.abi_encode());
+            }
+            let bytes: [u8; 32] = input.to_be_bytes();
+            scalar_inputs.push(bytes);
+        }
+
+        Ok((proof, scalar_inputs))
+    }
+
+    // keccak256(chain_id ++ contract ++ payload), chain_id as uint256: binds a signed payload
+    // to this deployment so it cannot be replayed on another chain or contract
+    fn domain_digest(&self, payload: &[u8]) -> FixedBytes<32> {
+        let mut data = Vec::with_capacity(52 + payload.len());
+        data.extend_from_slice(&U256::from(self.vm().chain_id()).to_be_bytes::<32>());
+        data.extend_from_slice(self.vm().contract_address().as_slice());
+        data.extend_from_slice(payload);
+        keccak256(data)
+    }
+
+    // EIP-191 personal_sign hash of a 32-byte digest
+    fn eth_signed_message_hash(digest: FixedBytes<32>) -> FixedBytes<32> {
+        let mut prefixed = [0u8; 60];
+        prefixed[0..28].copy_from_slice(b"\x19Ethereum Signed Message:\n32");
+        prefixed[28..60].copy_from_slice(digest.as_slice());
+        keccak256(prefixed)
+    }
+
+    // Signer of a 65-byte (r, s, v) signature over message_hash, via the ecrecover precompile
+    fn ecrecover(&self, message_hash: FixedBytes<32>, signature: &[u8]) -> Result<Address, Vec<u8>> {
+        if signature.len() != 65 {
+            return Err("Invalid signature length".into());
+        }
+
+        // Signature layout is r (32) + s (32) + v (1), v as 27/28 or 0/1
+        let v = match signature[64] {
+            0 | 1 => signature[64] + 27,
+            v => v,
+        };
+
+        // ecrecover input: hash (32) + v (32) + r (32) + s (32)
+        let mut calldata = [0u8; 128];
+        calldata[0..32].copy_from_slice(message_hash.as_slice());
+        calldata[63] = v;
+        calldata[64..128].copy_from_slice(&signature[0..64]);
+
+        let result = unsafe {
+            RawCall::new(self.vm()).call(Address::with_last_byte(EC_RECOVER_PRECOMPILE), &calldata)
+        }.map_err(|_| b"ecrecover precompile failed".to_vec())?;
+
+        // An invalid signature yields empty output rather than a revert
+        if result.len() != 32 {
+            return Err("Invalid signature".into());
+        }
+        Ok(Address::from_slice(&result[12..32]))
+    }
+
+    fn is_nullifier_consumed(&self, nullifier: U256) -> bool {
+        if self.bitmap_nullifiers.get() {
+            let word = self.nullifier_bitmap.get(nullifier >> 8);
+            return word.bit((nullifier & U256::from(0xff)).to::<usize>());
+        }
+        self.used_nullifiers.get(nullifier)
+    }
+
+    fn consume_nullifier(&mut self, nullifier: U256) {
+        if self.bitmap_nullifiers.get() {
+            let key = nullifier >> 8;
+            let mut word = self.nullifier_bitmap.get(key);
+            word.set_bit((nullifier & U256::from(0xff)).to::<usize>(), true);
+            self.nullifier_bitmap.setter(key).set(word);
+        } else {
+            self.used_nullifiers.setter(nullifier).set(true);
+        }
+    }
+
+    fn release_nullifier(&mut self, nullifier: U256) {
+        if self.bitmap_nullifiers.get() {
+            let key = nullifier >> 8;
+            let mut word = self.nullifier_bitmap.get(key);
+            word.set_bit((nullifier & U256::from(0xff)).to::<usize>(), false);
+            self.nullifier_bitmap.setter(key).set(word);
+        } else {
+            self.used_nullifiers.setter(nullifier).set(false);
+        }
+    }
+
+    fn approval_key(&self, action: FixedBytes<32>) -> FixedBytes<32> {
+        let mut data = [0u8; 64];
+        data[..32].copy_from_slice(action.as_slice());
+        data[32..].copy_from_slice(&self.admin_action_nonces.get(action).to_be_bytes::<32>());
+        keccak256(data)
+    }
+
+    // Owner-only while the approval flow is disabled; otherwise any admin (or the owner)
+    // may execute once enough admins approved, and the approvals are consumed.
+    fn authorize_dangerous_action(&mut self, action: FixedBytes<32>, name: &str) -> Result<(), Vec<u8>> {
+        let caller = self.vm().msg_sender();
+        let threshold = self.admin_threshold.get();
+        if threshold.is_zero() {
+            if caller != self.owner.get() {
+                return Err(alloc::format!("Only owner can {}", name).into());
+            }
+            return Ok(());
+        }
+        if caller != self.owner.get() && !self.admins.get(caller) {
+            return Err(alloc::format!("Only owner or admins can {}", name).into());
+        }
+        if self.action_approval_counts.get(self.approval_key(action)) < threshold {
+            return Err("Not enough admin approvals".into());
+        }
+        let nonce = self.admin_action_nonces.get(action);
+        self.admin_action_nonces.setter(action).set(nonce + U256::from(1));
+        Ok(())
+    }
+
+    fn ensure_not_decommissioned(&self) -> Result<(), Vec<u8>> {
+        if self.decommissioned.get() {
+            return Err("Contract decommissioned".into());
+        }
+        Ok(())
+    }
+
+    fn check_mint_cap(&self, to: Address) -> Result<(), Vec<u8>> {
+        let cap = self.max_mints_per_address.get();
+        if !cap.is_zero() && self.token_balances.get(to) >= cap {
+            return Err("Per-address mint cap reached".into());
+        }
+        Ok(())
+    }
+
+    fn update_min_required_balance(&mut self, new_min_balance: U256) {
+        let old_min_balance = self.min_required_balance.get();
+        self.min_required_balance.set(new_min_balance);
+        log(self.vm(), MinRequiredBalanceUpdated { oldBalance: old_min_balance, newBalance: new_min_balance });
+    }
+
+    fn ensure_not_paused(&self) -> Result<(), Vec<u8>> {
+        if self.paused.get() {
+            return Err(MintPaused {}.abi_encode());
+        }
+        Ok(())
+    }
+
+    // Gate shared by every mint entrypoint that carries neither an authorizer signature
+    // nor wallet consent; batch and bundle mints apply it to each recipient up front
+    fn ensure_plain_mint_allowed(&self, to: Address) -> Result<(), Vec<u8>> {
+        if self.require_authorizer.get() {
+            return Err("Mint requires an authorizer signature".into());
+        }
+        if self.requires_wallet_consent(to) {
+            return Err("Contract wallet recipient requires EIP-1271 consent".into());
+        }
+        Ok(())
+    }
+
+    fn requires_wallet_consent(&self, to: Address) -> bool {
+        self.require_wallet_consent.get() && self.vm().code_size(to) > 0
+    }
+
+    // Public signals the circuit actually exposes for the given mint inputs
+    fn circuit_public_inputs(&self, public_inputs: &[U256]) -> Result<Vec<U256>, Vec<u8>> {
+        if !self.poseidon_inputs_mode.get() {
+            return Ok(public_inputs.to_vec());
+        }
+
+        #[cfg(feature = "poseidon")]
+        {
+            let commitment = poseidon::hash(public_inputs)?;
+            Ok(alloc::vec![commitment])
+        }
+        #[cfg(not(feature = "poseidon"))]
+        {
+            Err("Poseidon support not compiled in".into())
+        }
+    }
+
+    fn send_ccip_message(&mut self, message: alloc::string::String) -> Result<FixedBytes<32>, Vec<u8>> {
+        let ccip_sender = ICCIPSender::new(self.ccip_sender.get());
+        
+        // Parâmetros da mensagem CCIP
+        let destination_chain_selector = self.ccip_destination_chain_selector.get().to::<u64>();
+        let receiver = self.ccip_receiver.get();
+
+        if self.ccip_native_fee.get() {
+            // Quote the bridge fee for this exact message so we neither under- nor overpay
+            let fee = ccip_sender.get_fee(
+                &*self.vm(),
+                Call::new(),
+                destination_chain_selector,
+                receiver,
+                message.clone()
+            )?;
+            // The caller's overpayment is still owed back to them. For batches this also holds
+            // back the other entries' mint fees, so the check errs on the safe side.
+            let pending_refund = self.vm().msg_value().saturating_sub(self.native_fee_per_mint());
+            let available = self.vm().balance(self.vm().contract_address()).saturating_sub(pending_refund);
+            if available < fee {
+                return Err("Insufficient balance for CCIP fee".into());
+            }
+
+            let config = Call::new_payable(self, fee);
+            let message_id = ccip_sender.send_message(
+                &*self.vm(),
+                config,
+                destination_chain_selector,
+                receiver,
+                message
+            )?;
+            return Ok(message_id);
+        }
+        
+        // Chamar sendMessage no contrato CCIP Sender
+        let config = Call::new();
+        let config_typed: Call<true> = unsafe { core::mem::transmute(config) };
+        let message_id = ccip_sender.send_message(
+            &*self.vm(),
+            config_typed,
+            destination_chain_selector,
+            receiver,
+            message
+        )?;
+        Ok(message_id)
+    }
+
+    fn build_ccip_message(&self, to: Address, nullifier: U256, timestamp: U256, token_id: U256) -> alloc::string::String {
+        let mut message = alloc::format!(
+            "user:0x{:x},nullifier:{},timestamp:{}",
+            to,
+            nullifier,
+            timestamp
+        );
+        if self.ccip_include_token_id.get() {
+            message.push_str(&alloc::format!(",tokenId:{}", token_id));
+        }
+        message
+    }
+
+    fn record_vk_rotation(&mut self, fingerprint: FixedBytes<32>) {
+        let block = U256::from(self.vm().block_number());
+        self.vk_history_blocks.push(block);
+        self.vk_history_fingerprints.push(fingerprint);
+    }
+
+    fn check_public_inputs(&self, to: Address, public_inputs: &[U256]) -> Result<(), Vec<u8>> {
+        if to == Address::ZERO {
+            return Err(ZeroRecipient {}.abi_encode());
+        }
+
+        // Check we have the expected number of public inputs (nullifier + 5 inputs,
+        // plus the block commitment when required)
+        let expected = self.expected_public_input_count();
+        if public_inputs.len() != expected {
+            return Err(InvalidPublicInputCount {
+                expected: U256::from(expected),
+                actual: U256::from(public_inputs.len()),
+            }.abi_encode());
+        }
+        
+        // Extract public inputs
+        // Order: [nullifier, min_required_balance, token_contract_hash, user_address_hash, timestamp, oracle_commitment]
+        let nullifier = public_inputs[0];
+        let min_balance_from_proof = public_inputs[1];
+        let proof_timestamp = public_inputs[4];
+
+        if nullifier.is_zero() {
+            return Err(ZeroNullifier {}.abi_encode());
+        }
+
+        // Bind the proof to the recipient so it cannot be redirected to another address
+        let user_hash = self.expected_user_hash(to)?;
+        if public_inputs[3] != user_hash {
+            return Err(UserHashMismatch { expected: user_hash, actual: public_inputs[3] }.abi_encode());
+        }
+        
+        // CRITICAL SECURITY CHECK: Validate min_required_balance is a threshold the contract accepts
+        // This prevents users from generating proofs with arbitrary lower thresholds
+        if !self.is_valid_threshold(min_balance_from_proof) {
+            return Err(InvalidThreshold { threshold: min_balance_from_proof }.abi_encode());
+        }
+        
+        // Live sanity check against a balance drained since the proof was generated
+        if self.require_live_balance.get() {
+            let balance = self.vm().balance(to) / U256::from(WEI_PER_BALANCE_UNIT);
+            if balance < min_balance_from_proof {
+                return Err(LiveBalanceTooLow { balance, threshold: min_balance_from_proof }.abi_encode());
+            }
+        }
+
+        // Check if nullifier has been used before (prevent replay attacks)
+        if self.is_nullifier_consumed(nullifier) {
+            return Err(NullifierAlreadyUsed { nullifier }.abi_encode());
+        }
+        
+        // SECURITY: Verify timestamp is recent (prevent using old proofs with stale data)
+        let current_time = U256::from(self.vm().block_timestamp());
+
+        // Oracle commitment must trace to a signed, unexpired oracle update
+        if self.require_signed_oracle_commitment.get() {
+            let valid_until = self.oracle_commitment_expiry.get(public_inputs[5]);
+            if valid_until < current_time {
+                return Err(OracleCommitmentExpired { commitment: public_inputs[5] }.abi_encode());
+            }
+        }
+        if self.require_block_hash.get() {
+            self.check_block_hash_commitment(public_inputs[6], public_inputs[7])?;
+        }
+        let max_age = self.max_proof_age.get();
+        
+        // Check if proof is too old
+        if current_time > proof_timestamp {
+            let age = current_time - proof_timestamp;
+            if age > max_age {
+                return Err(ProofExpired { age }.abi_encode());
+            }
+        } else {
+            // Proof timestamp is in the future - reject
+            return Err(ProofFromFuture { timestamp: proof_timestamp }.abi_encode());
+        }
+
+        Ok(())
+    }
+
+    /// Keep `kept` of the `paid` msg_value as collected mint fees and refund the rest to `payer`
+    fn settle_fee(&mut self, payer: Address, paid: U256, kept: U256) -> Result<(), Vec<u8>> {
+        self.collected_fees.set(self.collected_fees.get() + kept);
+        self.refund_fee(payer, paid - kept)
+    }
+
+    /// Send back a (part of a) mint fee that did not pay for a new token.
+    /// Any payable path that ends without minting must route msg_value through here.
+    fn refund_fee(&mut self, to: Address, amount: U256) -> Result<(), Vec<u8>> {
+        if amount.is_zero() {
+            return Ok(());
+        }
+
+        unsafe {
+            RawCall::new_with_value(self.vm(), amount).call(to, &[])
+        }.map_err(|_| b"Fee refund failed".to_vec())?;
+
+        log(self.vm(), FeeRefunded { to, amount });
+        Ok(())
+    }
+
+    fn groth16_verify(
+        &self,
+        proof: &ZKProof,
+        vk: &VerifyingKey,
+        public_inputs: &[Scalar],
+    ) -> Result<bool, Vec<u8>> {
+        let pairs = self.groth16_pairs(proof, vk, public_inputs)?;
+        PrecompileBackend::pairing_check(&*self.vm(), &pairs)
+    }
+
+    // Same pairing check as groth16_verify, returning the precompile's raw output
+    fn groth16_pairing_output(
+        &self,
+        proof: &ZKProof,
+        vk: &VerifyingKey,
+        public_inputs: &[Scalar],
+    ) -> Result<Vec<u8>, Vec<u8>> {
+        let pairs = self.groth16_pairs(proof, vk, public_inputs)?;
+        PrecompileBackend::pairing(&*self.vm(), &pairs)
+    }
+
+    // Compute vk_x = gamma_abc_g1[0] + sum(public_inputs[i] * gamma_abc_g1[i+1])
+    // snarkjs returns public signals as: [nullifier, min_required_balance, token_contract_hash, user_address_hash, timestamp, oracle_commitment]
+    // gamma_abc_g1 has: [constant, nullifier_coeff, min_req_coeff, token_coeff, user_coeff, timestamp_coeff, oracle_coeff]
+    fn compute_vk_x(&self, vk: &VerifyingKey, public_inputs: &[Scalar]) -> Result<G1Point, Vec<u8>> {
+        if public_inputs.len() + 1 != vk.gamma_abc_g1.len() {
+            return Err(InvalidPublicInputCount {
+                expected: U256::from(vk.gamma_abc_g1.len().saturating_sub(1)),
+                actual: U256::from(public_inputs.len()),
+            }.abi_encode());
+        }
+        let pairs: Vec<(Scalar, G1Point)> = public_inputs
+            .iter()
+            .zip(&vk.gamma_abc_g1[1..])
+            .map(|(input, point)| (*input, *point))
+            .collect();
+        let weighted_inputs = PrecompileBackend::msm(&*self.vm(), &pairs)?;
+        PrecompileBackend::ec_add(&*self.vm(), &vk.gamma_abc_g1[0], &weighted_inputs)
+    }
+
+    // Check N proofs for one VK with a single pairing call, via a random linear combination:
+    //   prod e(r_i*A_i, B_i) * e(-(sum r_i)*alpha, beta) * e(-sum r_i*vk_x_i, gamma) * e(-sum r_i*C_i, delta) == 1
+    // Each valid proof makes its factor of the product one whatever r_i is. If some proof is
+    // invalid, the product is one only if the r_i hit a root of a nonzero linear relation,
+    // which for 128-bit r_i happens with probability at most 2^-128. The r_i are derived from
+    // a hash of every proof and input, so they are fixed only after the proofs are, and a
+    // prover cannot pick proofs that cancel out.
+    // Cost: N + 3 pairings in one call instead of 4N over N calls, i.e. about 34k * (3N - 3)
+    // plus (N - 1) * 45k base gas saved, against 3N extra ecMul/ecAdd (~6.2k each).
+    fn groth16_verify_batch(
+        &self,
+        proofs: &[ZKProof],
+        vk: &VerifyingKey,
+        inputs: &[Vec<Scalar>],
+    ) -> Result<bool, Vec<u8>> {
+        if proofs.len() != inputs.len() {
+            return Err("Batch length mismatch".into());
+        }
+        if proofs.is_empty() {
+            return Err("Empty batch".into());
+        }
+
+        let mut transcript = Vec::with_capacity(proofs.len() * (256 + inputs[0].len() * 32));
+        for (proof, proof_inputs) in proofs.iter().zip(inputs) {
+            transcript.extend_from_slice(&proof.serialize());
+            for input in proof_inputs {
+                transcript.extend_from_slice(input);
+            }
+        }
+        let seed = keccak256(&transcript);
+
+        let mut pairs: Vec<(G1Point, G2Point)> = Vec::with_capacity(proofs.len() + 3);
+        let mut weighted_vk_x = Vec::with_capacity(proofs.len());
+        let mut weighted_c = Vec::with_capacity(proofs.len());
+        let mut r_sum = U256::ZERO;
+        for (i, (proof, proof_inputs)) in proofs.iter().zip(inputs).enumerate() {
+            let mut preimage = [0u8; 64];
+            preimage[..32].copy_from_slice(seed.as_slice());
+            preimage[32..].copy_from_slice(&U256::from(i).to_be_bytes::<32>());
+            let mut r: Scalar = [0u8; 32];
+            r[16..].copy_from_slice(&keccak256(preimage)[..16]);
+
+            pairs.push((PrecompileBackend::ec_mul(&*self.vm(), &r, &proof.a)?, proof.b));
+            weighted_vk_x.push((r, self.compute_vk_x(vk, proof_inputs)?));
+            weighted_c.push((r, proof.c));
+            r_sum = r_sum.add_mod(U256::from_be_bytes(r), BN254_SCALAR_MODULUS);
+        }
+
+        let alpha = PrecompileBackend::ec_mul(&*self.vm(), &r_sum.to_be_bytes::<32>(), &vk.alpha_g1)?;
+        let vk_x = PrecompileBackend::msm(&*self.vm(), &weighted_vk_x)?;
+        let c = PrecompileBackend::msm(&*self.vm(), &weighted_c)?;
+        pairs.push((PrecompileBackend::negate_g1_point(&alpha), vk.beta_g2));
+        pairs.push((PrecompileBackend::negate_g1_point(&vk_x), vk.gamma_g2));
+        pairs.push((PrecompileBackend::negate_g1_point(&c), vk.delta_g2));
+        PrecompileBackend::pairing_check(&*self.vm(), &pairs)
+    }
+
+    // The four Groth16 pairs: e(A, B) * e(-alpha, beta) * e(-vk_x, gamma) * e(-C, delta) = 1
+    fn groth16_pairs(
+        &self,
+        proof: &ZKProof,
+        vk: &VerifyingKey,
+        public_inputs: &[Scalar],
+    ) -> Result<[(G1Point, G2Point); 4], Vec<u8>> {
+        let vk_x = self.compute_vk_x(vk, public_inputs)?;
+
+        // Negate some points for the pairing check
+        let neg_alpha = PrecompileBackend::negate_g1_point(&vk.alpha_g1);
+        let neg_vk_x = PrecompileBackend::negate_g1_point(&vk_x);
+        let neg_c = PrecompileBackend::negate_g1_point(&proof.c);
+
+        Ok([
+            (proof.a, proof.b),
+            (neg_alpha, vk.beta_g2),
+            (neg_vk_x, vk.gamma_g2),
+            (neg_c, vk.delta_g2),
+        ])
+    }
+}
+
+//============================================================================
+// TESTS
+//============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use stylus_sdk::alloy_sol_types::{SolCall, SolEvent};
+    use stylus_sdk::testing::*;
+
+    sol! {
+        function onERC721Received(address operator, address from, uint256 tokenId, bytes data) external returns (bytes4);
+        function sendMessage(uint64 destinationChainSelector, address receiver, string text) external payable returns (bytes32 messageId);
+    }
+
+    const NOW: u64 = 1_700_000_000;
+    const MIN_BALANCE: u64 = 1_000_000;
+    const BASE_URI: &str = "ipfs://credentials/";
+
+    fn owner() -> Address {
+        Address::repeat_byte(0x11)
+    }
+
+    fn alice() -> Address {
+        Address::repeat_byte(0xa1)
+    }
+
+    fn bob() -> Address {
+        Address::repeat_byte(0xb0)
+    }
+
+    fn scalar(value: U256) -> Scalar {
+        value.to_be_bytes::<32>()
+    }
+
+    fn fr_neg(value: U256) -> U256 {
+        (BN254_SCALAR_MODULUS - value % BN254_SCALAR_MODULUS) % BN254_SCALAR_MODULUS
+    }
+
+    fn fr_sum(values: &[U256]) -> U256 {
+        values.iter().fold(U256::ZERO, |acc, value| acc.add_mod(*value, BN254_SCALAR_MODULUS))
+    }
+
+    // Affine G1 addition, to precompute what ecAdd/ecMul would return
+    fn g1_add(a: &G1Point, b: &G1Point) -> G1Point {
+        if PrecompileBackend::is_g1_zero(a) {
+            return *b;
+        }
+        if PrecompileBackend::is_g1_zero(b) {
+            return *a;
+        }
+        let p = BN254_FIELD_MODULUS;
+        let (x1, y1) = (U256::from_be_slice(&a[0..32]), U256::from_be_slice(&a[32..64]));
+        let (x2, y2) = (U256::from_be_slice(&b[0..32]), U256::from_be_slice(&b[32..64]));
+        let slope = if x1 == x2 {
+            if y1 != y2 || y1.is_zero() {
+                return [0u8; 64];
+            }
+            x1.mul_mod(x1, p).mul_mod(U256::from(3), p).mul_mod(fp_inv(y1.add_mod(y1, p)), p)
+        } else {
+            fp_sub(y2, y1).mul_mod(fp_inv(fp_sub(x2, x1)), p)
+        };
+        let x3 = fp_sub(fp_sub(slope.mul_mod(slope, p), x1), x2);
+        let y3 = fp_sub(slope.mul_mod(fp_sub(x1, x3), p), y1);
+        g1_from_snarkjs([x3, y3])
+    }
+
+    // k * G for the G1 generator G = (1, 2), by double-and-add
+    fn g1_mul(k: U256) -> G1Point {
+        let k = k % BN254_SCALAR_MODULUS;
+        let mut acc = [0u8; 64];
+        let mut base = PrecompileBackend::g1_generator();
+        for i in 0..k.bit_len() {
+            if k.bit(i) {
+                acc = g1_add(&acc, &base);
+            }
+            base = g1_add(&base, &base);
+        }
+        acc
+    }
+
+    // The G2 generator (the vk_gamma_2 snarkjs writes), in the precompile's limb order
+    fn g2_generator() -> G2Point {
+        g2_from_snarkjs([
+            [
+                "10857046999023057135944570762232829481370756359578518086990519993285655852781".parse().unwrap(),
+                "11559732032986387107991004021392285783925812861821192530917403151452391805634".parse().unwrap(),
+            ],
+            [
+                "8495653923123431417604973247489272438418190587263600148770280649306958101930".parse().unwrap(),
+                "4082367875863433681332203403145435568316851327593401208105741076214120093531".parse().unwrap(),
+            ],
+        ])
+    }
+
+    // arkworks compressed G1: little-endian x, flagged when y is the larger root
+    fn compress_g1(point: &G1Point) -> [u8; 32] {
+        let y = U256::from_be_slice(&point[32..64]);
+        let mut out = U256::from_be_slice(&point[0..32]).to_le_bytes::<32>();
+        if y > fp_sub(U256::ZERO, y) {
+            out[31] |= COMPRESSED_Y_NEGATIVE_FLAG;
+        }
+        out
+    }
+
+    // arkworks compressed G2: x.c0 then x.c1 little-endian, flag on x.c1, roots ordered c1 first
+    fn compress_g2(point: &G2Point) -> [u8; 64] {
+        let limb = |i: usize| U256::from_be_slice(&point[i * 32..(i + 1) * 32]);
+        let (x1, x0, y1, y0) = (limb(0), limb(1), limb(2), limb(3));
+        let mut out = [0u8; 64];
+        out[0..32].copy_from_slice(&x0.to_le_bytes::<32>());
+        out[32..64].copy_from_slice(&x1.to_le_bytes::<32>());
+        if (y1, y0) > (fp_sub(U256::ZERO, y1), fp_sub(U256::ZERO, y0)) {
+            out[63] |= COMPRESSED_Y_NEGATIVE_FLAG;
+        }
+        out
+    }
+
+    // Precompile calls are plain calls with no value attached
+    fn mock_call(vm: &TestVM, to: Address, calldata: Vec<u8>, result: Result<Vec<u8>, Vec<u8>>) {
+        vm.mock_call(to, calldata, U256::ZERO, result);
+    }
+
+    // ecPairing input for G1 points given by their discrete logs, each paired with the G2 generator
+    fn pairing_calldata(g1_dlogs: &[U256]) -> Vec<u8> {
+        let mut calldata = Vec::with_capacity(g1_dlogs.len() * 192);
+        for dlog in g1_dlogs {
+            calldata.extend_from_slice(&g1_mul(*dlog));
+            calldata.extend_from_slice(&g2_generator());
+        }
+        calldata
+    }
+
+    // Answer as the precompile would: with every G2 point the generator, the product of
+    // the pairings is e(G1, G2)^(sum of the G1 dlogs), i.e. one iff the dlogs sum to zero mod r
+    fn mock_pairing(vm: &TestVM, g1_dlogs: &[U256]) {
+        let is_one = fr_sum(g1_dlogs).is_zero();
+        let output = U256::from(is_one as u8).to_be_bytes::<32>().to_vec();
+        mock_call(vm, Address::with_last_byte(EC_PAIRING_PRECOMPILE), pairing_calldata(g1_dlogs), Ok(output));
+    }
+
+    // is_in_g2_subgroup's query for B = the G2 generator, which every test proof uses
+    fn mock_subgroup_check(vm: &TestVM) {
+        mock_pairing(vm, &[U256::from(1), fr_neg(U256::from(1))]);
+    }
+
+    // ecMul of the point with dlog `dlog` by `scalar`, returning the product's dlog
+    fn mock_ec_mul(vm: &TestVM, dlog: U256, scalar: U256) -> U256 {
+        let product = dlog.mul_mod(scalar, BN254_SCALAR_MODULUS);
+        let mut calldata = g1_mul(dlog).to_vec();
+        calldata.extend_from_slice(&scalar.to_be_bytes::<32>());
+        mock_call(vm, Address::with_last_byte(EC_MUL_PRECOMPILE), calldata, Ok(g1_mul(product).to_vec()));
+        product
+    }
+
+    fn mock_ec_add(vm: &TestVM, a: U256, b: U256) -> U256 {
+        let sum = fr_sum(&[a, b]);
+        let mut calldata = g1_mul(a).to_vec();
+        calldata.extend_from_slice(&g1_mul(b));
+        mock_call(vm, Address::with_last_byte(EC_ADD_PRECOMPILE), calldata, Ok(g1_mul(sum).to_vec()));
+        sum
+    }
+
+    // The calls PrecompileBackend::msm makes for (scalar, dlog) terms: zero terms are skipped
+    // and the first term is added to the zero accumulator, which ec_add answers without a call
+    fn mock_msm(vm: &TestVM, terms: &[(U256, U256)]) -> U256 {
+        terms.iter().filter(|(scalar, dlog)| !scalar.is_zero() && !dlog.is_zero()).fold(U256::ZERO, |acc, (scalar, dlog)| {
+            let term = mock_ec_mul(vm, *dlog, *scalar);
+            if acc.is_zero() { term } else { mock_ec_add(vm, acc, term) }
+        })
+    }
+
+    // Synthetic circuit whose points all have known discrete logs: alpha and gamma_abc[0] are
+    // multiples of G1, every G2 point is the generator and the gamma_abc points weighting the
+    // inputs are at infinity, so vk_x is gamma_abc[0] whatever the inputs and costs no
+    // precompile call. A proof (a, c) then passes iff a == alpha + vk_x + c (mod r).
+    struct TestCircuit {
+        alpha: U256,
+        vk_x: U256,
+    }
+
+    // A proof by the discrete logs of A and C; B is the G2 generator
+    struct TestProof {
+        a: U256,
+        c: U256,
+    }
+
+    impl TestCircuit {
+        fn new(alpha: u64, vk_x: u64) -> Self {
+            TestCircuit { alpha: U256::from(alpha), vk_x: U256::from(vk_x) }
+        }
+
+        fn vk(&self) -> VerifyingKey {
+            self.vk_for_inputs(EXPECTED_PUBLIC_INPUTS)
+        }
+
+        // Key for a circuit with `inputs` public signals
+        fn vk_for_inputs(&self, inputs: usize) -> VerifyingKey {
+            let mut gamma_abc_g1 = vec![[0u8; 64]; inputs + 1];
+            gamma_abc_g1[0] = g1_mul(self.vk_x);
+            VerifyingKey {
+                alpha_g1: g1_mul(self.alpha),
+                beta_g2: g2_generator(),
+                gamma_g2: g2_generator(),
+                delta_g2: g2_generator(),
+                gamma_abc_g1,
+            }
+        }
+
+        fn proof(&self, c: u64) -> TestProof {
+            let c = U256::from(c);
+            TestProof { a: fr_sum(&[self.alpha, self.vk_x, c]), c }
+        }
+
+        // G1 dlogs of groth16_pairs: A, -alpha, -vk_x, -C
+        fn pairing_dlogs(&self, proof: &TestProof) -> [U256; 4] {
+            [proof.a, fr_neg(self.alpha), fr_neg(self.vk_x), fr_neg(proof.c)]
+        }
+    }
+
+    impl TestProof {
+        // Same C with A off by one, so the pairing check fails
+        fn forged(&self) -> TestProof {
+            TestProof { a: fr_sum(&[self.a, U256::from(1)]), c: self.c }
+        }
+
+        fn zk_proof(&self) -> ZKProof {
+            ZKProof { a: g1_mul(self.a), b: g2_generator(), c: g1_mul(self.c) }
+        }
+
+        fn encode(&self) -> Vec<u8> {
+            self.zk_proof().serialize().to_vec()
+        }
+
+        // The 128-byte arkworks compressed form of the same proof
+        fn encode_compressed(&self) -> Vec<u8> {
+            let proof = self.zk_proof();
+            let mut data = compress_g1(&proof.a).to_vec();
+            data.extend_from_slice(&compress_g2(&proof.b));
+            data.extend_from_slice(&compress_g1(&proof.c));
+            data
+        }
+    }
+
+    // Mock the pairing calls verify_proof makes: the subgroup check on B, then Groth16
+    fn mock_verification(vm: &TestVM, circuit: &TestCircuit, proof: &TestProof) {
+        mock_subgroup_check(vm);
+        mock_pairing(vm, &circuit.pairing_dlogs(proof));
+    }
+
+    // A contract at `receiver` answering onERC721Received for `token_id` sent by `from` with `selector`
+    fn mock_receiver(vm: &TestVM, receiver: Address, from: Address, token_id: U256, selector: [u8; 4]) {
+        vm.set_code(receiver, vec![0]);
+        let calldata = onERC721ReceivedCall { operator: from, from, tokenId: token_id, data: Default::default() }.abi_encode();
+        let mut output = vec![0u8; 32];
+        output[..4].copy_from_slice(&selector);
+        mock_call(vm, receiver, calldata, Ok(output));
+    }
+
+    // Replay groth16_verify_batch's transcript and coefficients, mocking each call it makes
+    fn mock_batch_verification(vm: &TestVM, circuit: &TestCircuit, proofs: &[TestProof], inputs: &[Vec<U256>]) {
+        mock_subgroup_check(vm);
+
+        let mut transcript = Vec::new();
+        for (proof, proof_inputs) in proofs.iter().zip(inputs) {
+            transcript.extend_from_slice(&proof.encode());
+            for input in proof_inputs {
+                transcript.extend_from_slice(&input.to_be_bytes::<32>());
+            }
+        }
+        let seed = keccak256(&transcript);
+
+        let mut dlogs = Vec::with_capacity(proofs.len() + 3);
+        let mut weighted_vk_x = Vec::with_capacity(proofs.len());
+        let mut weighted_c = Vec::with_capacity(proofs.len());
+        let mut r_sum = U256::ZERO;
+        for (i, proof) in proofs.iter().enumerate() {
+            let mut preimage = seed.to_vec();
+            preimage.extend_from_slice(&U256::from(i).to_be_bytes::<32>());
+            let r = U256::from_be_slice(&keccak256(preimage)[..16]);
+
+            dlogs.push(mock_ec_mul(vm, proof.a, r));
+            weighted_vk_x.push((r, circuit.vk_x));
+            weighted_c.push((r, proof.c));
+            r_sum = fr_sum(&[r_sum, r]);
+        }
+
+        dlogs.push(fr_neg(mock_ec_mul(vm, circuit.alpha, r_sum)));
+        dlogs.push(fr_neg(mock_msm(vm, &weighted_vk_x)));
+        dlogs.push(fr_neg(mock_msm(vm, &weighted_c)));
+        mock_pairing(vm, &dlogs);
+    }
+
+    // Answer the sendMessage that minting `token_id` for public_inputs(contract, to, nullifier)
+    // makes to the CCIP sender at `sender`, bridging to (receiver, chain_selector)
+    fn mock_ccip_send(
+        vm: &TestVM,
+        contract: &ZKMintContract,
+        (sender, receiver, chain_selector): (Address, Address, u64),
+        (to, nullifier, token_id): (Address, u64, u64),
+        result: Result<Vec<u8>, Vec<u8>>,
+    ) {
+        let text = contract.build_ccip_message(to, U256::from(nullifier), U256::from(NOW - 60), U256::from(token_id));
+        let calldata = sendMessageCall { destinationChainSelector: chain_selector, receiver, text }.abi_encode();
+        mock_call(vm, sender, calldata, result);
+    }
+
+    // Deployed by owner() with the compiled-in key; CCIP is paused so mints need no router
+    fn deploy(vm: &TestVM) -> ZKMintContract {
+        vm.set_sender(owner());
+        vm.set_block_timestamp(NOW);
+        let mut contract = ZKMintContract::from(vm);
+        contract
+            .constructor(
+                owner(),
+                U256::from(MIN_BALANCE),
+                FixedBytes::ZERO,
+                false,
+                "ZK Credential".into(),
+                "ZKC".into(),
+                BASE_URI.into(),
+            )
+            .unwrap();
+        contract.set_ccip_paused(true).unwrap();
+        contract
+    }
+
+    // deploy() with TestCircuit::new(5, 7) as the active key
+    fn setup() -> (TestVM, ZKMintContract, TestCircuit) {
+        let vm = TestVM::default();
+        let mut contract = deploy(&vm);
+        let circuit = TestCircuit::new(5, 7);
+        contract.set_verifying_key(circuit.vk().serialize(), FixedBytes::ZERO).unwrap();
+        (vm, contract, circuit)
+    }
+
+    // Minute-old public inputs binding `nullifier` to `to`, passing every mint check
+    fn public_inputs(contract: &ZKMintContract, to: Address, nullifier: u64) -> Vec<U256> {
+        vec![
+            U256::from(nullifier),
+            U256::from(MIN_BALANCE),
+            U256::from(0xc0ffee),
+            contract.expected_user_hash(to).unwrap(),
+            U256::from(NOW - 60),
+            U256::from(7),
+        ]
+    }
+
+    // Whether `event` was logged, topics and data alike
+    fn emitted<E: SolEvent>(vm: &TestVM, event: &E) -> bool {
+        let log = event.encode_log_data();
+        vm.get_emitted_logs()
+            .iter()
+            .any(|(topics, data)| topics.as_slice() == log.topics() && data.as_slice() == log.data.as_ref())
+    }
+
+    // Mint `nullifier` to `to` with a valid proof, sent by `to`
+    fn mint(vm: &TestVM, contract: &mut ZKMintContract, circuit: &TestCircuit, to: Address, nullifier: u64) -> Result<U256, Vec<u8>> {
+        let proof = circuit.proof(3);
+        mock_verification(vm, circuit, &proof);
+        let inputs = public_inputs(contract, to, nullifier);
+        vm.set_sender(to);
+        contract.mint_with_zk_proof(to, proof.encode(), inputs)
+    }
+
+    // ========================================================================
+    // SCALARS AND POINTS
+    // ========================================================================
+
+    #[test]
+    fn reduce_scalar_maps_group_order_to_zero() {
+        assert_eq!(PrecompileBackend::reduce_scalar(&scalar(BN254_SCALAR_MODULUS)), [0u8; 32]);
+        assert_eq!(
+            PrecompileBackend::reduce_scalar(&scalar(BN254_SCALAR_MODULUS + U256::from(5))),
+            scalar(U256::from(5))
+        );
+    }
+
+    #[test]
+    fn ec_mul_checked_rejects_group_order() {
+        let vm = TestVM::default();
+        let generator = PrecompileBackend::g1_generator();
+        let order = scalar(BN254_SCALAR_MODULUS);
+        assert_eq!(
+            PrecompileBackend::ec_mul_checked(&vm, &order, &generator),
+            Err(b"Scalar exceeds group order".to_vec())
+        );
+
+        // Unchecked, r * G reduces to the identity without reaching the precompile
+        let product = PrecompileBackend::ec_mul(&vm, &order, &generator).unwrap();
+        assert!(PrecompileBackend::g1_eq(&product, &[0u8; 64]));
+    }
+
+    #[test]
+    fn g1_eq_compares_encodings() {
+        let generator = PrecompileBackend::g1_generator();
+        assert!(PrecompileBackend::g1_eq(&generator, &g1_mul(U256::from(1))));
+        assert!(!PrecompileBackend::g1_eq(&generator, &PrecompileBackend::negate_g1_point(&generator)));
+        assert!(PrecompileBackend::g1_eq(&[0u8; 64], &[0u8; 64]));
+        assert!(!PrecompileBackend::g1_eq(&[0u8; 64], &generator));
+        assert!(!PrecompileBackend::g1_eq(&generator, &[0u8; 64]));
+
+        // (1 + p, 2) names the same field element but is not the precompiles' encoding
+        let shifted = g1_from_snarkjs([U256::from(1) + BN254_FIELD_MODULUS, U256::from(2)]);
+        assert!(!PrecompileBackend::g1_eq(&shifted, &generator));
+    }
+
+    #[test]
+    fn g1_test_arithmetic_matches_known_points() {
+        let double = g1_from_snarkjs([
+            "1368015179489954701390400359078579693043519447331113978918064868415326638035".parse().unwrap(),
+            "9918110051302171585080402603319702774565515993150576347155970296011118125764".parse().unwrap(),
+        ]);
+        assert!(PrecompileBackend::g1_eq(&g1_mul(U256::from(2)), &double));
+        assert!(PrecompileBackend::g1_eq(&g1_add(&g1_mul(U256::from(2)), &g1_mul(U256::from(3))), &g1_mul(U256::from(5))));
+
+        let point = g1_mul(U256::from(12345));
+        assert!(PrecompileBackend::is_on_curve_g1(&point));
+        assert!(PrecompileBackend::g1_eq(&g1_add(&point, &PrecompileBackend::negate_g1_point(&point)), &[0u8; 64]));
+    }
+
+    // ========================================================================
+    // VERIFYING KEYS
+    // ========================================================================
+
+    #[test]
+    fn deserialize_checked_rejects_tampered_gamma_abc_point() {
+        let vk = VerifyingKey {
+            alpha_g1: g1_mul(U256::from(2)),
+            beta_g2: g2_generator(),
+            gamma_g2: g2_generator(),
+            delta_g2: g2_generator(),
+            gamma_abc_g1: (1..=7u64).map(|k| g1_mul(U256::from(k))).collect(),
+        };
+        let mut vk_bytes = vk.serialize();
+        assert_eq!(VerifyingKey::deserialize_checked(&vk_bytes), Ok(vk));
+
+        // Last byte of gamma_abc[3].y: 452 header bytes, then three 64-byte points
+        vk_bytes[452 + 3 * 64 + 63] ^= 1;
+        assert_eq!(
+            VerifyingKey::deserialize_checked(&vk_bytes),
+            Err(String::from("gamma_abc[3] not on curve"))
+        );
+    }
+
+    // ========================================================================
+    // PAIRING OUTPUT
+    // ========================================================================
+
+    #[test]
+    fn verify_proof_accepts_valid_and_rejects_forged_proof() {
+        let (vm, contract, circuit) = setup();
+        let proof = circuit.proof(3);
+        let forged = proof.forged();
+        mock_verification(&vm, &circuit, &proof);
+        mock_verification(&vm, &circuit, &forged);
+
+        let inputs = public_inputs(&contract, alice(), 1);
+        assert_eq!(contract.verify_proof(proof.encode(), inputs.clone()), Ok(true));
+        assert_eq!(contract.verify_proof(forged.encode(), inputs), Ok(false));
+    }
+
+    #[test]
+    fn proof_verifies_under_either_g2_limb_order() {
+        let (vm, mut contract, circuit) = setup();
+        let proof = circuit.proof(3);
+        mock_verification(&vm, &circuit, &proof);
+        let inputs = public_inputs(&contract, alice(), 1);
+        assert_eq!(contract.verify_proof(proof.encode(), inputs.clone()), Ok(true));
+
+        // Same proof with B written (x_c0, x_c1, y_c0, y_c1), as libraries other than the precompile emit it
+        let mut library_order = proof.encode();
+        for coordinate in library_order[64..192].chunks_exact_mut(64) {
+            let (c1, c0) = coordinate.split_at_mut(32);
+            c1.swap_with_slice(c0);
+        }
+        contract.set_g2_library_order(true).unwrap();
+        assert_eq!(
+            ZKProof::deserialize(&library_order, true),
+            ZKProof::deserialize(&proof.encode(), false)
+        );
+        assert_eq!(contract.verify_proof(library_order, inputs), Ok(true));
+    }
+
+    #[test]
+    fn compressed_and_uncompressed_proof_verify_alike() {
+        let (vm, contract, circuit) = setup();
+        let proof = circuit.proof(3);
+        let forged = proof.forged();
+        mock_verification(&vm, &circuit, &proof);
+        mock_verification(&vm, &circuit, &forged);
+
+        assert_eq!(ZKProof::deserialize_compressed(&proof.encode_compressed()), Ok(proof.zk_proof()));
+        let inputs = public_inputs(&contract, alice(), 1);
+        for (valid, candidate) in [(true, &proof), (false, &forged)] {
+            assert_eq!(contract.verify_proof(candidate.encode(), inputs.clone()), Ok(valid));
+            assert_eq!(contract.verify_proof(candidate.encode_compressed(), inputs.clone()), Ok(valid));
+        }
+    }
+
+    #[test]
+    fn g1_curve_check_accepts_generator_and_infinity_only() {
+        let generator = PrecompileBackend::g1_generator();
+        assert!(PrecompileBackend::is_on_curve_g1(&generator));
+        assert!(PrecompileBackend::is_on_curve_g1(&[0u8; 64]));
+
+        let mut tampered = generator;
+        tampered[63] ^= 1;
+        assert!(!PrecompileBackend::is_on_curve_g1(&tampered));
+    }
+
+    #[test]
+    fn deserialize_rejects_proof_point_off_curve() {
+        let (_vm, _contract, circuit) = setup();
+        let mut data = circuit.proof(3).encode();
+        data[63] ^= 1;
+        assert_eq!(ZKProof::deserialize(&data, false), Err("Proof point A not on curve"));
+    }
+
+    #[test]
+    fn deserialize_compressed_handles_infinity_and_off_curve_x() {
+        let mut data = TestCircuit::new(5, 7).proof(3).encode_compressed();
+        data[31] = COMPRESSED_INFINITY_FLAG;
+        data[..31].fill(0);
+        assert_eq!(ZKProof::deserialize_compressed(&data).map(|proof| proof.a), Ok([0u8; 64]));
+
+        // x = 0 has no root: 3 is not a square mod p
+        data[31] = 0;
+        assert_eq!(ZKProof::deserialize_compressed(&data), Err("Compressed G1 point not on curve"));
+        assert_eq!(ZKProof::deserialize_compressed(&data[..127]), Err("Invalid compressed proof length"));
+    }
+
+    #[test]
+    fn verify_proof_rejects_short_pairing_output() {
+        let (vm, contract, circuit) = setup();
+        let proof = circuit.proof(3);
+        mock_subgroup_check(&vm);
+
+        let inputs = public_inputs(&contract, alice(), 1);
+        for output in [Vec::new(), vec![0u8; 31]] {
+            let calldata = pairing_calldata(&circuit.pairing_dlogs(&proof));
+            mock_call(&vm, Address::with_last_byte(EC_PAIRING_PRECOMPILE), calldata, Ok(output));
+            assert_eq!(
+                contract.verify_proof(proof.encode(), inputs.clone()),
+                Err(b"Unexpected pairing precompile output length".to_vec())
+            );
+        }
+    }
+
+    #[test]
+    fn verify_proof_rejects_pairing_word_other_than_zero_or_one() {
+        let (vm, contract, circuit) = setup();
+        let proof = circuit.proof(3);
+        mock_subgroup_check(&vm);
+        let calldata = pairing_calldata(&circuit.pairing_dlogs(&proof));
+        let output = U256::from(2).to_be_bytes::<32>().to_vec();
+        mock_call(&vm, Address::with_last_byte(EC_PAIRING_PRECOMPILE), calldata, Ok(output));
+
+        assert_eq!(
+            contract.verify_proof(proof.encode(), public_inputs(&contract, alice(), 1)),
+            Err(b"Unexpected pairing precompile output".to_vec())
+        );
+    }
+
+    #[test]
+    fn pairing_check_of_infinity_pair_is_true() {
+        let vm = TestVM::default();
+        // e(O, O) is one; the precompile answers the all-zero input with 1
+        let output = U256::from(1).to_be_bytes::<32>().to_vec();
+        mock_call(&vm, Address::with_last_byte(EC_PAIRING_PRECOMPILE), vec![0u8; 192], Ok(output));
+        assert_eq!(PrecompileBackend::pairing_check(&vm, &[([0u8; 64], [0u8; 128])]), Ok(true));
+    }
+
+    #[test]
+    fn pairing_check_of_mismatched_pairs_is_false() {
+        let vm = TestVM::default();
+        let (g1, g2) = (g1_mul(U256::from(1)), g2_generator());
+        // e(G1, G2) * e(G1, G2) = e(G1, G2)^2 != 1, while e(G1, G2) * e(-G1, G2) = 1
+        mock_pairing(&vm, &[U256::from(1), U256::from(1)]);
+        mock_pairing(&vm, &[U256::from(1), fr_neg(U256::from(1))]);
+        assert_eq!(PrecompileBackend::pairing_check(&vm, &[(g1, g2), (g1, g2)]), Ok(false));
+        let neg_g1 = g1_mul(fr_neg(U256::from(1)));
+        assert_eq!(PrecompileBackend::pairing_check(&vm, &[(g1, g2), (neg_g1, g2)]), Ok(true));
+    }
+
+    #[test]
+    fn subgroup_check_parses_pairing_output_strictly() {
+        let vm = TestVM::default();
+        let point = g2_generator();
+        let calldata = pairing_calldata(&[U256::from(1), fr_neg(U256::from(1))]);
+        let pairing = Address::with_last_byte(EC_PAIRING_PRECOMPILE);
+
+        for output in [Vec::new(), vec![0u8; 31]] {
+            mock_call(&vm, pairing, calldata.clone(), Ok(output));
+            assert!(PrecompileBackend::is_in_g2_subgroup(&vm, &point).is_err());
+        }
+
+        // A rejected input is still the answer "not in the subgroup"
+        mock_call(&vm, pairing, calldata.clone(), Err(Vec::new()));
+        assert_eq!(PrecompileBackend::is_in_g2_subgroup(&vm, &point), Ok(false));
+
+        mock_subgroup_check(&vm);
+        assert_eq!(PrecompileBackend::is_in_g2_subgroup(&vm, &point), Ok(true));
+    }
+
+    // ========================================================================
+    // RECIPIENT BINDING
+    // ========================================================================
+
+    #[test]
+    fn user_hash_is_address_hash_reduced_mod_r() {
+        let (_vm, contract, _circuit) = setup();
+        let hash = U256::from_be_bytes(keccak256(alice()).0);
+        assert_eq!(contract.expected_user_hash(alice()), Ok(hash % BN254_SCALAR_MODULUS));
+    }
+
+    #[test]
+    fn mint_accepts_matching_recipient() {
+        let (vm, mut contract, circuit) = setup();
+        assert_eq!(mint(&vm, &mut contract, &circuit, alice(), 1), Ok(U256::from(1)));
+        assert_eq!(contract.owner_of(U256::from(1)), Ok(alice()));
+    }
+
+    #[test]
+    fn mint_logs_transfer_from_zero_address() {
+        let (vm, mut contract, circuit) = setup();
+        let token_id = mint(&vm, &mut contract, &circuit, alice(), 1).unwrap();
+
+        // Indexed from, to and tokenId all go to topics, leaving no data
+        let (topics, data) = vm.get_emitted_logs().into_iter().find(|(topics, _)| topics[0] == Transfer::SIGNATURE_HASH).unwrap();
+        assert_eq!(topics[1], Address::ZERO.into_word());
+        assert_eq!(topics[2], alice().into_word());
+        assert_eq!(U256::from_be_bytes(topics[3].0), U256::from(1));
+        assert!(data.is_empty());
+        assert!(emitted(&vm, &Transfer { from: Address::ZERO, to: alice(), tokenId: token_id }));
+    }
+
+    #[test]
+    fn mint_rejects_proof_redirected_to_another_recipient() {
+        let (vm, mut contract, circuit) = setup();
+        let proof = circuit.proof(3);
+        mock_verification(&vm, &circuit, &proof);
+        let inputs = public_inputs(&contract, alice(), 1);
+
+        vm.set_sender(bob());
+        let err = contract.mint_with_zk_proof(bob(), proof.encode(), inputs).unwrap_err();
+        let expected = UserHashMismatch {
+            expected: contract.expected_user_hash(bob()).unwrap(),
+            actual: contract.expected_user_hash(alice()).unwrap(),
+        };
+        assert_eq!(err, expected.abi_encode());
+    }
+
+    // ========================================================================
+    // SAFE TRANSFERS
+    // ========================================================================
+
+    #[test]
+    fn safe_transfer_to_eoa_skips_receiver_check() {
+        let (vm, mut contract, circuit) = setup();
+        let token_id = mint(&vm, &mut contract, &circuit, alice(), 1).unwrap();
+
+        assert_eq!(contract.safe_transfer_from(alice(), bob(), token_id, Vec::new().into()), Ok(()));
+        assert_eq!(contract.owner_of(token_id), Ok(bob()));
+    }
+
+    #[test]
+    fn safe_transfer_to_contract_accepts_magic_value() {
+        let (vm, mut contract, circuit) = setup();
+        let token_id = mint(&vm, &mut contract, &circuit, alice(), 1).unwrap();
+        let receiver = Address::repeat_byte(0xc0);
+        mock_receiver(&vm, receiver, alice(), token_id, ERC721_RECEIVED_MAGIC_VALUE);
+
+        assert_eq!(contract.safe_transfer_from(alice(), receiver, token_id, Vec::new().into()), Ok(()));
+        assert_eq!(contract.owner_of(token_id), Ok(receiver));
+    }
+
+    #[test]
+    fn safe_transfer_to_contract_rejects_wrong_selector() {
+        let (vm, mut contract, circuit) = setup();
+        let token_id = mint(&vm, &mut contract, &circuit, alice(), 1).unwrap();
+        let receiver = Address::repeat_byte(0xc0);
+        mock_receiver(&vm, receiver, alice(), token_id, [0xde, 0xad, 0xbe, 0xef]);
+
+        assert_eq!(
+            contract.safe_transfer_from(alice(), receiver, token_id, Vec::new().into()),
+            Err(b"Transfer to non ERC721Receiver implementer".to_vec())
+        );
+    }
+
+    // ========================================================================
+    // METADATA
+    // ========================================================================
+
+    #[test]
+    fn token_uri_appends_token_id_to_base_uri() {
+        let (vm, mut contract, circuit) = setup();
+        let token_id = mint(&vm, &mut contract, &circuit, alice(), 1).unwrap();
+
+        assert_eq!(contract.name(), "ZK Credential");
+        assert_eq!(contract.symbol(), "ZKC");
+        assert_eq!(contract.token_uri(token_id), Ok(String::from("ipfs://credentials/1")));
+    }
+
+    #[test]
+    fn token_uri_rejects_nonexistent_token() {
+        let (_vm, contract, _circuit) = setup();
+        assert_eq!(contract.token_uri(U256::from(1)), Err(b"Token does not exist".to_vec()));
+    }
+
+    #[test]
+    fn supports_interface_answers_per_id() {
+        let (_vm, contract, _circuit) = setup();
+        // ERC165, ERC721 and ERC721Metadata, spelled out rather than through the constants
+        for id in [[0x01, 0xff, 0xc9, 0xa7], [0x80, 0xac, 0x58, 0xcd], [0x5b, 0x5e, 0x13, 0x9f]] {
+            assert!(contract.supports_interface(FixedBytes::from(id)));
+        }
+        for id in [[0xff, 0xff, 0xff, 0xff], [0x12, 0x34, 0x56, 0x78]] {
+            assert!(!contract.supports_interface(FixedBytes::from(id)));
+        }
+    }
+
+    #[test]
+    fn safe_transfer_without_data_transfers_to_eoa() {
+        let (vm, mut contract, circuit) = setup();
+        let token_id = mint(&vm, &mut contract, &circuit, alice(), 1).unwrap();
+
+        assert_eq!(contract.safe_transfer_from_without_data(alice(), bob(), token_id), Ok(()));
+        assert_eq!(contract.owner_of(token_id), Ok(bob()));
+        assert_eq!(contract.balance_of(alice()), U256::ZERO);
+        assert_eq!(contract.balance_of(bob()), U256::from(1));
+    }
+
+    // ========================================================================
+    // MINT CAP
+    // ========================================================================
+
+    #[test]
+    fn mint_cap_allows_mints_below_cap() {
+        let (vm, mut contract, circuit) = setup();
+        contract.set_max_mints_per_address(U256::from(2)).unwrap();
+
+        assert_eq!(mint(&vm, &mut contract, &circuit, alice(), 1), Ok(U256::from(1)));
+        assert_eq!(mint(&vm, &mut contract, &circuit, alice(), 2), Ok(U256::from(2)));
+    }
+
+    #[test]
+    fn mint_cap_rejects_mint_at_cap() {
+        let (vm, mut contract, circuit) = setup();
+        contract.set_max_mints_per_address(U256::from(1)).unwrap();
+
+        assert_eq!(mint(&vm, &mut contract, &circuit, alice(), 1), Ok(U256::from(1)));
+        assert_eq!(mint(&vm, &mut contract, &circuit, alice(), 2), Err(b"Per-address mint cap reached".to_vec()));
+        // The cap is per address
+        assert_eq!(mint(&vm, &mut contract, &circuit, bob(), 3), Ok(U256::from(2)));
+    }
+
+    #[test]
+    fn mint_cap_rejects_mint_above_lowered_cap() {
+        let (vm, mut contract, circuit) = setup();
+        mint(&vm, &mut contract, &circuit, alice(), 1).unwrap();
+        mint(&vm, &mut contract, &circuit, alice(), 2).unwrap();
+
+        vm.set_sender(owner());
+        contract.set_max_mints_per_address(U256::from(1)).unwrap();
+        assert_eq!(mint(&vm, &mut contract, &circuit, alice(), 3), Err(b"Per-address mint cap reached".to_vec()));
+    }
+
+    // ========================================================================
+    // CIRCUIT REGISTRY
+    // ========================================================================
+
+    #[test]
+    fn verify_proof_for_circuit_uses_each_registered_key() {
+        let (vm, mut contract, first) = setup();
+        let second = TestCircuit::new(11, 13);
+        contract.register_verifying_key(U256::from(1), first.vk().serialize()).unwrap();
+        contract.register_verifying_key(U256::from(2), second.vk().serialize()).unwrap();
+
+        let first_proof = first.proof(3);
+        let second_proof = second.proof(4);
+        mock_verification(&vm, &first, &first_proof);
+        mock_verification(&vm, &second, &second_proof);
+        // Each proof checked against the other circuit's key
+        mock_verification(&vm, &second, &first_proof);
+        mock_verification(&vm, &first, &second_proof);
+
+        let inputs = public_inputs(&contract, alice(), 1);
+        let verify = |circuit_id: u64, proof: &TestProof| {
+            contract.verify_proof_for_circuit(U256::from(circuit_id), proof.encode(), inputs.clone())
+        };
+        assert_eq!(verify(1, &first_proof), Ok(true));
+        assert_eq!(verify(2, &second_proof), Ok(true));
+        assert_eq!(verify(2, &first_proof), Ok(false));
+        assert_eq!(verify(1, &second_proof), Ok(false));
+        assert_eq!(verify(3, &first_proof), Err(b"Unknown circuit id".to_vec()));
+    }
+
+    #[test]
+    fn mint_for_circuit_uses_registered_key() {
+        let (vm, mut contract, _active) = setup();
+        let circuit = TestCircuit::new(11, 13);
+        contract.register_verifying_key(U256::from(2), circuit.vk().serialize()).unwrap();
+        let proof = circuit.proof(4);
+        mock_verification(&vm, &circuit, &proof);
+
+        let inputs = public_inputs(&contract, alice(), 1);
+        vm.set_sender(alice());
+        assert_eq!(
+            contract.mint_with_zk_proof_for_circuit(alice(), proof.encode(), inputs, U256::from(2)),
+            Ok(U256::from(1))
+        );
+    }
+
+    // ========================================================================
+    // CUSTOM ERRORS
+    // ========================================================================
+
+    // Revert data of a custom error: its selector, then the ABI-encoded fields
+    fn assert_custom_error<E: SolError>(result: Result<impl core::fmt::Debug, Vec<u8>>, expected: E) {
+        let err = result.unwrap_err();
+        assert_eq!(err[..4], E::SELECTOR);
+        assert_eq!(err, expected.abi_encode());
+    }
+
+    #[test]
+    fn mint_reports_wrong_input_count_as_custom_error() {
+        let (vm, mut contract, circuit) = setup();
+        let proof = circuit.proof(3);
+        let mut inputs = public_inputs(&contract, alice(), 1);
+        inputs.pop();
+
+        vm.set_sender(alice());
+        assert_custom_error(
+            contract.mint_with_zk_proof(alice(), proof.encode(), inputs),
+            InvalidPublicInputCount { expected: U256::from(6), actual: U256::from(5) },
+        );
+        assert_custom_error(
+            contract.mint_with_zk_proof(alice(), proof.encode(), Vec::new()),
+            InvalidPublicInputCount { expected: U256::from(6), actual: U256::ZERO },
+        );
+    }
+
+    #[test]
+    fn mint_reports_replayed_nullifier_as_custom_error() {
+        let (vm, mut contract, circuit) = setup();
+        mint(&vm, &mut contract, &circuit, alice(), 1).unwrap();
+
+        assert_custom_error(
+            mint(&vm, &mut contract, &circuit, alice(), 1),
+            NullifierAlreadyUsed { nullifier: U256::from(1) },
+        );
+    }
+
+    #[test]
+    fn verify_proof_reports_input_outside_field_as_custom_error() {
+        let (_vm, contract, circuit) = setup();
+        let mut inputs = public_inputs(&contract, alice(), 1);
+        inputs[2] = BN254_SCALAR_MODULUS;
+
+        assert_custom_error(
+            contract.verify_proof(circuit.proof(3).encode(), inputs),
+            PublicInputNotInField { input: BN254_SCALAR_MODULUS },
+        );
+    }
+
+    #[test]
+    fn verify_proof_checks_field_bound_at_the_modulus_edges() {
+        let (vm, contract, circuit) = setup();
+        let proof = circuit.proof(3);
+        mock_verification(&vm, &circuit, &proof);
+        let r_plus_one = BN254_SCALAR_MODULUS + U256::from(1);
+        let r_minus_one = BN254_SCALAR_MODULUS - U256::from(1);
+
+        let mut inputs = public_inputs(&contract, alice(), 1);
+        inputs[2] = r_plus_one;
+        assert_custom_error(contract.verify_proof(proof.encode(), inputs.clone()), PublicInputNotInField { input: r_plus_one });
+
+        // r - 1 is the largest canonical field element and must be accepted
+        inputs[2] = r_minus_one;
+        assert_eq!(contract.verify_proof(proof.encode(), inputs), Ok(true));
+    }
+
+    #[test]
+    fn compute_vk_x_reports_wrong_input_count_as_custom_error() {
+        let (vm, contract, circuit) = setup();
+        mock_subgroup_check(&vm);
+        let mut inputs = public_inputs(&contract, alice(), 1);
+        inputs.pop();
+
+        assert_custom_error(
+            contract.verify_proof(circuit.proof(3).encode(), inputs),
+            InvalidPublicInputCount { expected: U256::from(6), actual: U256::from(5) },
+        );
+    }
+
+    #[test]
+    fn verify_proof_reports_b_outside_subgroup_as_custom_error() {
+        let (vm, contract, circuit) = setup();
+        let calldata = pairing_calldata(&[U256::from(1), fr_neg(U256::from(1))]);
+        mock_call(&vm, Address::with_last_byte(EC_PAIRING_PRECOMPILE), calldata, Err(Vec::new()));
+
+        assert_custom_error(
+            contract.verify_proof(circuit.proof(3).encode(), public_inputs(&contract, alice(), 1)),
+            ProofPointNotInSubgroup {},
+        );
+    }
+
+    #[test]
+    fn verify_proof_reports_missing_key_as_custom_error() {
+        let vm = TestVM::default();
+        let contract = deploy(&vm);
+        let circuit = TestCircuit::new(5, 7);
+        let vk = VerifyingKey { gamma_abc_g1: Vec::new(), ..circuit.vk() };
+
+        assert_custom_error(
+            contract.verify_proof_with_key(&vk, &circuit.proof(3).encode(), &[]),
+            VerifierNotInitialized {},
+        );
+    }
+
+    // ========================================================================
+    // BATCH VERIFICATION
+    // ========================================================================
+
+    #[test]
+    fn verify_proof_batch_accepts_all_valid_proofs() {
+        let (vm, contract, circuit) = setup();
+        let proofs = [circuit.proof(3), circuit.proof(4)];
+        let inputs = vec![public_inputs(&contract, alice(), 1), public_inputs(&contract, bob(), 2)];
+        mock_batch_verification(&vm, &circuit, &proofs, &inputs);
+
+        let encoded = proofs.iter().map(TestProof::encode).collect();
+        assert_eq!(contract.verify_proof_batch(encoded, inputs), Ok(true));
+    }
+
+    #[test]
+    fn verify_proof_batch_rejects_one_invalid_proof() {
+        let (vm, contract, circuit) = setup();
+        let proofs = [circuit.proof(3), circuit.proof(4).forged()];
+        let inputs = vec![public_inputs(&contract, alice(), 1), public_inputs(&contract, bob(), 2)];
+        mock_batch_verification(&vm, &circuit, &proofs, &inputs);
+
+        let encoded = proofs.iter().map(TestProof::encode).collect();
+        assert_eq!(contract.verify_proof_batch(encoded, inputs), Ok(false));
+    }
+
+    // ========================================================================
+    // MIN BALANCE TIMELOCK
+    // ========================================================================
+
+    #[test]
+    fn min_balance_change_applies_immediately_without_delay() {
+        let (vm, mut contract, _circuit) = setup();
+        contract.set_min_required_balance(U256::from(2_000_000)).unwrap();
+
+        assert_eq!(contract.get_min_required_balance(), U256::from(2_000_000));
+        assert_eq!(contract.get_pending_min_balance(), (U256::ZERO, U256::ZERO));
+        assert!(emitted(
+            &vm,
+            &MinRequiredBalanceUpdated { oldBalance: U256::from(MIN_BALANCE), newBalance: U256::from(2_000_000) }
+        ));
+    }
+
+    #[test]
+    fn min_balance_change_waits_for_delay() {
+        let (vm, mut contract, _circuit) = setup();
+        contract.set_min_balance_delay(U256::from(3600)).unwrap();
+        contract.set_min_required_balance(U256::from(2_000_000)).unwrap();
+
+        let effective_at = U256::from(NOW + 3600);
+        assert_eq!(contract.get_min_required_balance(), U256::from(MIN_BALANCE));
+        assert_eq!(contract.get_pending_min_balance(), (U256::from(2_000_000), effective_at));
+        assert!(emitted(&vm, &MinRequiredBalanceScheduled { newBalance: U256::from(2_000_000), effectiveAt: effective_at }));
+
+        vm.set_block_timestamp(NOW + 3599);
+        assert_eq!(contract.apply_pending_min_balance(), Err(b"Min balance timelock not expired".to_vec()));
+
+        vm.set_block_timestamp(NOW + 3600);
+        assert_eq!(contract.apply_pending_min_balance(), Ok(()));
+        assert_eq!(contract.get_min_required_balance(), U256::from(2_000_000));
+        assert_eq!(contract.get_pending_min_balance(), (U256::ZERO, U256::ZERO));
+        assert!(emitted(
+            &vm,
+            &MinRequiredBalanceUpdated { oldBalance: U256::from(MIN_BALANCE), newBalance: U256::from(2_000_000) }
+        ));
+    }
+
+    // ========================================================================
+    // OWNERSHIP
+    // ========================================================================
+
+    #[test]
+    fn pending_owner_accepts_ownership() {
+        let (vm, mut contract, _circuit) = setup();
+        contract.transfer_ownership(alice()).unwrap();
+        assert_eq!(contract.get_owner(), owner());
+        assert_eq!(contract.get_pending_owner(), alice());
+
+        vm.set_sender(alice());
+        assert_eq!(contract.accept_ownership(), Ok(()));
+        assert_eq!(contract.get_owner(), alice());
+        assert_eq!(contract.get_pending_owner(), Address::ZERO);
+        assert!(emitted(&vm, &OwnershipTransferred { previousOwner: owner(), newOwner: alice() }));
+    }
+
+    #[test]
+    fn accept_ownership_rejects_other_callers() {
+        let (vm, mut contract, _circuit) = setup();
+        contract.transfer_ownership(alice()).unwrap();
+
+        vm.set_sender(bob());
+        assert_eq!(contract.accept_ownership(), Err(b"Only pending owner can accept_ownership".to_vec()));
+        assert_eq!(contract.get_owner(), owner());
+    }
+
+    #[test]
+    fn admin_rights_follow_accepted_ownership() {
+        let (vm, mut contract, _circuit) = setup();
+        contract.transfer_ownership(alice()).unwrap();
+        vm.set_sender(alice());
+        contract.accept_ownership().unwrap();
+
+        vm.set_sender(owner());
+        assert_eq!(
+            contract.set_min_required_balance(U256::from(2_000_000)),
+            Err(b"Only owner can set_min_required_balance".to_vec())
+        );
+        vm.set_sender(alice());
+        assert_eq!(contract.set_min_required_balance(U256::from(2_000_000)), Ok(()));
+        assert_eq!(contract.get_min_required_balance(), U256::from(2_000_000));
+    }
+
+    // ========================================================================
+    // BATCH MINTS
+    // ========================================================================
+
+    #[test]
+    fn mint_batch_mints_each_recipient() {
+        let (vm, mut contract, circuit) = setup();
+        let proofs = [circuit.proof(3), circuit.proof(4)];
+        for proof in &proofs {
+            mock_verification(&vm, &circuit, proof);
+        }
+        let inputs = vec![public_inputs(&contract, alice(), 1), public_inputs(&contract, bob(), 2)];
+
+        let encoded = proofs.iter().map(TestProof::encode).collect();
+        assert_eq!(
+            contract.mint_batch_with_zk_proofs(vec![alice(), bob()], encoded, inputs),
+            Ok(vec![U256::from(1), U256::from(2)])
+        );
+        assert_eq!(contract.owner_of(U256::from(1)), Ok(alice()));
+        assert_eq!(contract.owner_of(U256::from(2)), Ok(bob()));
+    }
+
+    #[test]
+    fn mint_batch_rejects_empty_and_duplicate_nullifier_batches() {
+        let (_vm, mut contract, circuit) = setup();
+        assert_eq!(
+            contract.mint_batch_with_zk_proofs(Vec::new(), Vec::new(), Vec::new()),
+            Err(b"Empty batch".to_vec())
+        );
+
+        let encoded = vec![circuit.proof(3).encode(), circuit.proof(4).encode()];
+        let inputs = vec![public_inputs(&contract, alice(), 1), public_inputs(&contract, bob(), 1)];
+        assert_eq!(
+            contract.mint_batch_with_zk_proofs(vec![alice(), bob()], encoded, inputs),
+            Err(b"Duplicate nullifier in batch".to_vec())
+        );
+    }
+
+    #[test]
+    fn mint_batch_rejects_batch_with_invalid_proof() {
+        let (vm, mut contract, circuit) = setup();
+        let proofs = [circuit.proof(3), circuit.proof(4).forged()];
+        for proof in &proofs {
+            mock_verification(&vm, &circuit, proof);
+        }
+        let inputs = vec![public_inputs(&contract, alice(), 1), public_inputs(&contract, bob(), 2)];
+
+        let encoded = proofs.iter().map(TestProof::encode).collect();
+        assert_eq!(
+            contract.mint_batch_with_zk_proofs(vec![alice(), bob()], encoded, inputs),
+            Err(InvalidProof {}.abi_encode())
+        );
+    }
+
+    // ========================================================================
+    // BURN
+    // ========================================================================
+
+    #[test]
+    fn burn_removes_token_and_keeps_nullifier_consumed() {
+        let (vm, mut contract, circuit) = setup();
+        let token_id = mint(&vm, &mut contract, &circuit, alice(), 1).unwrap();
+
+        assert_eq!(contract.burn(token_id), Ok(()));
+        assert_eq!(contract.owner_of(token_id), Err(b"Token does not exist".to_vec()));
+        assert_eq!(contract.balance_of(alice()), U256::ZERO);
+        assert_eq!(contract.total_supply(), U256::ZERO);
+        assert_eq!(contract.total_burned(), U256::from(1));
+        assert!(emitted(&vm, &Transfer { from: alice(), to: Address::ZERO, tokenId: token_id }));
+
+        assert!(contract.is_nullifier_used(U256::from(1)));
+        assert_eq!(
+            mint(&vm, &mut contract, &circuit, alice(), 1),
+            Err(NullifierAlreadyUsed { nullifier: U256::from(1) }.abi_encode())
+        );
+    }
+
+    #[test]
+    fn burn_rejects_caller_neither_owner_nor_approved() {
+        let (vm, mut contract, circuit) = setup();
+        let token_id = mint(&vm, &mut contract, &circuit, alice(), 1).unwrap();
+
+        vm.set_sender(bob());
+        assert_eq!(contract.burn(token_id), Err(b"Caller is not owner nor approved".to_vec()));
+        assert_eq!(contract.owner_of(token_id), Ok(alice()));
+    }
+
+    #[test]
+    fn verify_proof_rejects_a_or_c_at_infinity() {
+        let (_vm, contract, circuit) = setup();
+        let inputs = public_inputs(&contract, alice(), 1);
+        let valid = circuit.proof(3);
+
+        for proof in [TestProof { a: U256::ZERO, c: valid.c }, TestProof { a: valid.a, c: U256::ZERO }] {
+            assert_eq!(
+                contract.verify_proof(proof.encode(), inputs.clone()),
+                Err(ProofPointAtInfinity {}.abi_encode())
+            );
+        }
+    }
+
+    // ========================================================================
+    // NULLIFIER ADMINISTRATION
+    // ========================================================================
+
+    #[test]
+    fn invalidated_nullifier_cannot_mint() {
+        let (vm, mut contract, circuit) = setup();
+        assert_eq!(contract.invalidate_nullifier(U256::from(1)), Ok(()));
+        assert!(contract.is_nullifier_used(U256::from(1)));
+        assert!(emitted(&vm, &NullifierInvalidated { nullifier: U256::from(1) }));
+
+        assert_eq!(
+            mint(&vm, &mut contract, &circuit, alice(), 1),
+            Err(NullifierAlreadyUsed { nullifier: U256::from(1) }.abi_encode())
+        );
+    }
+
+    #[test]
+    fn nullifier_used_is_logged_by_mint_and_invalidation() {
+        let (vm, mut contract, circuit) = setup();
+        let token_id = mint(&vm, &mut contract, &circuit, alice(), 1).unwrap();
+        assert!(emitted(&vm, &NullifierUsed { nullifier: U256::from(1), recipient: alice(), tokenId: token_id }));
+
+        vm.set_sender(owner());
+        contract.invalidate_nullifier(U256::from(2)).unwrap();
+        assert!(emitted(&vm, &NullifierUsed { nullifier: U256::from(2), recipient: owner(), tokenId: U256::ZERO }));
+    }
+
+    #[test]
+    fn invalidate_nullifier_is_owner_only() {
+        let (vm, mut contract, _circuit) = setup();
+        vm.set_sender(alice());
+        assert_eq!(
+            contract.invalidate_nullifier(U256::from(1)),
+            Err(b"Only owner can invalidate_nullifier".to_vec())
+        );
+        assert!(!contract.is_nullifier_used(U256::from(1)));
+    }
+
+    #[test]
+    fn cleared_nullifier_mints_again_only_when_enabled() {
+        let (vm, mut contract, circuit) = setup();
+        contract.invalidate_nullifier(U256::from(1)).unwrap();
+        assert_eq!(contract.clear_nullifier(U256::from(1)), Err(b"Nullifier clearing disabled".to_vec()));
+
+        contract.set_nullifier_clearing_enabled(true).unwrap();
+        assert_eq!(contract.clear_nullifier(U256::from(1)), Ok(()));
+        assert!(!contract.is_nullifier_used(U256::from(1)));
+        assert_eq!(mint(&vm, &mut contract, &circuit, alice(), 1), Ok(U256::from(1)));
+    }
+
+    // ========================================================================
+    // INPUT DISCOVERY
+    // ========================================================================
+
+    #[test]
+    fn advertised_input_count_and_min_balance_match_mint_checks() {
+        let (vm, mut contract, _circuit) = setup();
+        let inputs = public_inputs(&contract, alice(), 1);
+        assert_eq!(contract.get_public_input_count(), U256::from(inputs.len()));
+        assert_eq!(contract.get_public_input_count(), U256::from(6));
+        assert_eq!(contract.validate_public_inputs(alice(), inputs.clone()), Ok(()));
+
+        let mut extra = inputs;
+        extra.push(U256::ZERO);
+        assert_eq!(
+            contract.validate_public_inputs(alice(), extra),
+            Err(InvalidPublicInputCount { expected: U256::from(6), actual: U256::from(7) }.abi_encode())
+        );
+
+        assert_eq!(contract.get_expected_min_balance(), contract.get_min_required_balance());
+        vm.set_sender(owner());
+        contract.set_min_required_balance(U256::from(2_000_000)).unwrap();
+        assert_eq!(contract.get_expected_min_balance(), U256::from(2_000_000));
+    }
+
+    // ========================================================================
+    // SNARKJS PROOFS
+    // ========================================================================
+
+    #[test]
+    fn deserialize_snarkjs_puts_g2_imaginary_limb_first() {
+        let (_vm, contract, _circuit) = setup();
+        // vk_delta_2 from circuits/verification_key.json, as snarkjs prints it: [[x_c0, x_c1], [y_c0, y_c1]]
+        let b: [[U256; 2]; 2] = [
+            [
+                "19931499454173432591644369569403061098615602907310131120378927645093111662781".parse().unwrap(),
+                "18627640765194692663840960935741939051902326296480243538133538489018361285527".parse().unwrap(),
+            ],
+            [
+                "12017156728926638724640181664811809213595328157053410596256983520145623013535".parse().unwrap(),
+                "21105829974222870477725096880814417658435913604977017768494916471385144849562".parse().unwrap(),
+            ],
+        ];
+        let a = g1_mul(U256::from(1));
+        let c = g1_mul(U256::from(2));
+        let point = |p: &G1Point| [U256::from_be_slice(&p[..32]), U256::from_be_slice(&p[32..])];
+
+        let proof_data = contract.deserialize_snarkjs(point(&a), b, point(&c));
+        assert_eq!(proof_data.len(), 256);
+        assert_eq!(proof_data[64..96], b[0][1].to_be_bytes::<32>());
+
+        let proof = ZKProof::deserialize(&proof_data, false).unwrap();
+        assert_eq!(proof, ZKProof { a, b: g2_from_snarkjs(b), c });
+        assert!(PrecompileBackend::is_on_curve_g2(&proof.b));
+
+        // The same limbs left in snarkjs order are not a point on the twist
+        let mut unswapped = [0u8; 128];
+        for (chunk, limb) in unswapped.chunks_exact_mut(32).zip([b[0][0], b[0][1], b[1][0], b[1][1]]) {
+            chunk.copy_from_slice(&limb.to_be_bytes::<32>());
+        }
+        assert!(!PrecompileBackend::is_on_curve_g2(&unswapped));
+    }
+
+    // ========================================================================
+    // VERIFYING KEY UPGRADES
+    // ========================================================================
+
+    #[test]
+    fn set_verifying_key_switches_the_accepted_circuit() {
+        let (vm, mut contract, old) = setup();
+        assert_eq!(mint(&vm, &mut contract, &old, alice(), 1), Ok(U256::from(1)));
+
+        let new = TestCircuit::new(11, 13);
+        vm.set_sender(owner());
+        contract.set_verifying_key(new.vk().serialize(), FixedBytes::ZERO).unwrap();
+        assert_eq!(contract.get_verifying_key_bytes(), new.vk().serialize());
+
+        // A proof for the old circuit checked against the new key
+        let old_proof = old.proof(3);
+        mock_verification(&vm, &new, &old_proof);
+        let inputs = public_inputs(&contract, alice(), 2);
+        vm.set_sender(alice());
+        assert_eq!(
+            contract.mint_with_zk_proof(alice(), old_proof.encode(), inputs),
+            Err(InvalidProof {}.abi_encode())
+        );
+
+        assert_eq!(mint(&vm, &mut contract, &new, alice(), 2), Ok(U256::from(2)));
+    }
+
+    #[test]
+    fn empty_verifying_key_restores_compiled_key() {
+        let (vm, mut contract, _circuit) = setup();
+        vm.set_sender(owner());
+        contract.set_verifying_key(Vec::new(), FixedBytes::ZERO).unwrap();
+        assert_eq!(contract.get_verifying_key_bytes(), get_verifying_key().serialize());
+    }
+
+    #[test]
+    fn get_vk_gamma_abc_returns_points_and_rejects_out_of_range_index() {
+        let (_vm, contract, _circuit) = setup();
+        let last = U256::from(EXPECTED_PUBLIC_INPUTS);
+
+        assert_eq!(contract.get_vk_gamma_abc(U256::ZERO), Ok(g1_mul(U256::from(7))));
+        assert_eq!(contract.get_vk_gamma_abc(last), Ok([0u8; 64]));
+        assert_eq!(contract.get_vk_gamma_abc(last + U256::from(1)), Err(b"gamma_abc index out of range".to_vec()));
+        assert_eq!(contract.get_vk_gamma_abc(U256::MAX), Err(b"gamma_abc index out of range".to_vec()));
+    }
+
+    #[test]
+    fn serialized_snarkjs_key_deserializes_to_same_points() {
+        let (_vm, contract, _circuit) = setup();
+        let g1 = |point: G1Point| [U256::from_be_slice(&point[0..32]), U256::from_be_slice(&point[32..64])];
+        let limb = |point: &G2Point, i: usize| U256::from_be_slice(&point[i * 32..(i + 1) * 32]);
+        let g2 = g2_generator();
+        // Back to snarkjs [[x_c0, x_c1], [y_c0, y_c1]] from the precompile's (x_c1, x_c0, y_c1, y_c0)
+        let g2_snarkjs = [[limb(&g2, 1), limb(&g2, 0)], [limb(&g2, 3), limb(&g2, 2)]];
+        let gamma_abc = vec![g1(g1_mul(U256::from(7))), g1(g1_mul(U256::from(11))), g1(g1_mul(U256::from(13)))];
+
+        let blob = contract.serialize_verifying_key(g1(g1_mul(U256::from(5))), g2_snarkjs, g2_snarkjs, g2_snarkjs, gamma_abc);
+        assert_eq!(blob.len(), 452 + 64 * 3);
+        assert_eq!(
+            VerifyingKey::deserialize(&blob),
+            Ok(VerifyingKey {
+                alpha_g1: g1_mul(U256::from(5)),
+                beta_g2: g2,
+                gamma_g2: g2,
+                delta_g2: g2,
+                gamma_abc_g1: vec![g1_mul(U256::from(7)), g1_mul(U256::from(11)), g1_mul(U256::from(13))],
+            })
+        );
+    }
+
+    #[test]
+    fn verifying_key_must_match_input_layout() {
+        let (_vm, mut contract, circuit) = setup();
+        assert_eq!(contract.get_vk_gamma_abc_len(), U256::from(EXPECTED_PUBLIC_INPUTS + 1));
+
+        let mut vk = circuit.vk();
+        vk.gamma_abc_g1.push([0u8; 64]);
+        assert_eq!(
+            contract.set_verifying_key(vk.serialize(), FixedBytes::ZERO),
+            Err(b"VK gamma_abc count mismatch".to_vec())
+        );
+        assert_eq!(contract.get_verifying_key_bytes(), circuit.vk().serialize());
+    }
+
+    #[test]
+    fn require_block_hash_checks_active_key_layout() {
+        let (_vm, mut contract, _circuit) = setup();
+        assert_eq!(contract.set_require_block_hash(true), Err(b"VK gamma_abc count mismatch".to_vec()));
+        assert!(!contract.get_require_block_hash());
+        assert_eq!(contract.set_require_block_hash(false), Ok(()));
+    }
+
+    #[test]
+    fn placeholder_key_lets_input_layout_change() {
+        let (_vm, mut contract, circuit) = setup();
+        let placeholder = VerifyingKey { gamma_abc_g1: Vec::new(), ..circuit.vk() };
+        contract.set_verifying_key(placeholder.serialize(), FixedBytes::ZERO).unwrap();
+        assert_eq!(contract.set_require_block_hash(true), Ok(()));
+
+        let vk = circuit.vk_for_inputs(EXPECTED_PUBLIC_INPUTS + 2);
+        assert_eq!(contract.set_verifying_key(vk.serialize(), FixedBytes::ZERO), Ok(()));
+        assert_eq!(contract.get_vk_gamma_abc_len(), U256::from(EXPECTED_PUBLIC_INPUTS + 3));
+    }
+
+    #[cfg(feature = "poseidon")]
+    #[test]
+    fn mint_in_poseidon_inputs_mode_verifies_single_commitment() {
+        let (vm, mut contract, circuit) = setup();
+        let placeholder = VerifyingKey { gamma_abc_g1: Vec::new(), ..circuit.vk() };
+        contract.set_verifying_key(placeholder.serialize(), FixedBytes::ZERO).unwrap();
+        contract.set_poseidon_inputs_mode(true).unwrap();
+        contract.set_verifying_key(circuit.vk_for_inputs(1).serialize(), FixedBytes::ZERO).unwrap();
+
+        // The six mint inputs still go through every check, then reach the circuit as one hash
+        assert_eq!(mint(&vm, &mut contract, &circuit, alice(), 1), Ok(U256::from(1)));
+        assert_eq!(contract.owner_of(U256::from(1)), Ok(alice()));
+    }
+
+    #[cfg(feature = "poseidon")]
+    #[test]
+    fn poseidon_inputs_mode_checks_active_key_layout() {
+        let (_vm, mut contract, _circuit) = setup();
+        assert_eq!(contract.set_poseidon_inputs_mode(true), Err(b"VK gamma_abc count mismatch".to_vec()));
+    }
+
+    // ========================================================================
+    // FEES
+    // ========================================================================
+
+    #[test]
+    fn withdraw_fees_sends_only_collected_mint_fees() {
+        let (vm, mut contract, circuit) = setup();
+        contract.set_mint_fee(U256::from(100)).unwrap();
+
+        vm.set_value(U256::from(100));
+        mint(&vm, &mut contract, &circuit, alice(), 1).unwrap();
+        mint(&vm, &mut contract, &circuit, bob(), 2).unwrap();
+        vm.set_value(U256::ZERO);
+        assert_eq!(contract.get_collected_fees(), U256::from(200));
+
+        vm.set_sender(alice());
+        assert_eq!(contract.withdraw_fees(alice()), Err(b"Only owner can withdraw_fees".to_vec()));
+
+        vm.set_sender(owner());
+        vm.mock_call(owner(), Vec::new(), U256::from(200), Ok(Vec::new()));
+        assert_eq!(contract.withdraw_fees(owner()), Ok(()));
+        assert_eq!(contract.get_collected_fees(), U256::ZERO);
+    }
+
+    // ========================================================================
+    // CCIP
+    // ========================================================================
+
+    const DEFAULT_CCIP_ROUTE: (Address, Address, u64) =
+        (DEFAULT_CCIP_SENDER, DEFAULT_CCIP_RECEIVER, DEFAULT_CCIP_DESTINATION_CHAIN_SELECTOR);
+
+    #[test]
+    fn optional_ccip_failure_is_logged_and_mint_succeeds() {
+        let (vm, mut contract, circuit) = setup();
+        contract.set_ccip_paused(false).unwrap();
+        contract.set_ccip_required(false).unwrap();
+        mock_ccip_send(&vm, &contract, DEFAULT_CCIP_ROUTE, (alice(), 1, 1), Err(b"router down".to_vec()));
+
+        assert_eq!(mint(&vm, &mut contract, &circuit, alice(), 1), Ok(U256::from(1)));
+        assert!(emitted(&vm, &CcipSendFailed { tokenId: U256::from(1), nullifier: U256::from(1) }));
+    }
+
+    #[test]
+    fn required_ccip_failure_reverts_mint() {
+        let (vm, mut contract, circuit) = setup();
+        contract.set_ccip_paused(false).unwrap();
+        mock_ccip_send(&vm, &contract, DEFAULT_CCIP_ROUTE, (alice(), 1, 1), Err(b"router down".to_vec()));
+
+        assert!(mint(&vm, &mut contract, &circuit, alice(), 1).is_err());
+        assert!(!emitted(&vm, &CcipSendFailed { tokenId: U256::from(1), nullifier: U256::from(1) }));
+    }
+
+    #[test]
+    fn ccip_message_goes_to_newly_configured_sender() {
+        let (vm, mut contract, circuit) = setup();
+        let route = (Address::repeat_byte(0xcc), Address::repeat_byte(0xdd), 42);
+        contract.set_ccip_config(route.0, route.1, route.2).unwrap();
+        contract.set_ccip_paused(false).unwrap();
+        // Only the new route answers, and CCIP is required, so the mint proves the call went there
+        mock_ccip_send(&vm, &contract, route, (alice(), 1, 1), Ok(vec![0x11; 32]));
+
+        assert_eq!(mint(&vm, &mut contract, &circuit, alice(), 1), Ok(U256::from(1)));
+        assert!(!emitted(&vm, &CcipSendFailed { tokenId: U256::from(1), nullifier: U256::from(1) }));
+    }
+
+    #[test]
+    fn ccip_preview_includes_token_id_only_when_enabled() {
+        let (_vm, mut contract, _circuit) = setup();
+        let preview = |contract: &ZKMintContract| {
+            alloc::string::String::from_utf8(contract.preview_ccip_message(alice(), U256::from(1), U256::from(NOW))).unwrap()
+        };
+
+        assert!(!preview(&contract).contains("tokenId:"));
+        contract.set_ccip_include_token_id(true).unwrap();
+        assert!(preview(&contract).ends_with(",tokenId:1"));
+    }
+
+    // ========================================================================
+    // PAUSE
+    // ========================================================================
+
+    #[test]
+    fn paused_contract_rejects_mints_until_unpaused() {
+        let (vm, mut contract, circuit) = setup();
+        assert_eq!(contract.pause(), Ok(()));
+        assert!(contract.is_paused());
+        assert_eq!(mint(&vm, &mut contract, &circuit, alice(), 1), Err(MintPaused {}.abi_encode()));
+
+        vm.set_sender(owner());
+        assert_eq!(contract.unpause(), Ok(()));
+        assert!(emitted(&vm, &MintingPaused { paused: false }));
+        assert_eq!(mint(&vm, &mut contract, &circuit, alice(), 1), Ok(U256::from(1)));
+    }
+
+    #[test]
+    fn pause_and_unpause_are_owner_only() {
+        let (vm, mut contract, _circuit) = setup();
+        vm.set_sender(alice());
+        assert_eq!(contract.pause(), Err(b"Only owner can pause".to_vec()));
+
+        vm.set_sender(owner());
+        contract.pause().unwrap();
+        vm.set_sender(alice());
+        assert_eq!(contract.unpause(), Err(b"Only owner can unpause".to_vec()));
+        assert!(contract.is_paused());
+    }
+
+    // ========================================================================
+    // PROOF AGE
+    // ========================================================================
+
+    #[test]
+    fn widened_proof_age_accepts_older_proof() {
+        let (vm, mut contract, circuit) = setup();
+        let proof = circuit.proof(3);
+        mock_verification(&vm, &circuit, &proof);
+        let mut inputs = public_inputs(&contract, alice(), 1);
+        inputs[4] = U256::from(NOW - 600);
+
+        vm.set_sender(alice());
+        assert_eq!(
+            contract.mint_with_zk_proof(alice(), proof.encode(), inputs.clone()),
+            Err(ProofExpired { age: U256::from(600) }.abi_encode())
+        );
+
+        vm.set_sender(owner());
+        contract.set_max_proof_age(U256::from(3600)).unwrap();
+        vm.set_sender(alice());
+        assert_eq!(contract.mint_with_zk_proof(alice(), proof.encode(), inputs), Ok(U256::from(1)));
+    }
+
+    #[test]
+    fn max_proof_age_is_capped_at_one_day() {
+        let (_vm, mut contract, _circuit) = setup();
+        assert_eq!(contract.set_max_proof_age(U256::from(86400)), Ok(()));
+        assert_eq!(
+            contract.set_max_proof_age(U256::from(86401)),
+            Err(b"Max proof age must be between 1 second and one day".to_vec())
+        );
+        assert_eq!(contract.get_max_proof_age(), U256::from(86400));
+    }
+
+    // ========================================================================
+    // APPROVALS AND TRANSFERS
+    // ========================================================================
+
+    fn carol() -> Address {
+        Address::repeat_byte(0xca)
+    }
+
+    #[test]
+    fn owner_approves_spender() {
+        let (vm, mut contract, circuit) = setup();
+        let token_id = mint(&vm, &mut contract, &circuit, alice(), 1).unwrap();
+
+        assert_eq!(contract.approve(bob(), token_id), Ok(()));
+        assert_eq!(contract.get_approved(token_id), Ok(bob()));
+        assert!(emitted(&vm, &Approval { owner: alice(), approved: bob(), tokenId: token_id }));
+    }
+
+    #[test]
+    fn operator_approves_spender() {
+        let (vm, mut contract, circuit) = setup();
+        let token_id = mint(&vm, &mut contract, &circuit, alice(), 1).unwrap();
+        contract.set_approval_for_all(bob(), true).unwrap();
+
+        vm.set_sender(bob());
+        assert_eq!(contract.approve(carol(), token_id), Ok(()));
+        assert_eq!(contract.get_approved(token_id), Ok(carol()));
+        assert!(emitted(&vm, &Approval { owner: alice(), approved: carol(), tokenId: token_id }));
+    }
+
+    #[test]
+    fn approve_rejects_random_caller() {
+        let (vm, mut contract, circuit) = setup();
+        let token_id = mint(&vm, &mut contract, &circuit, alice(), 1).unwrap();
+
+        vm.set_sender(bob());
+        assert_eq!(contract.approve(bob(), token_id), Err(b"Caller is not owner nor approved operator".to_vec()));
+        assert_eq!(contract.get_approved(token_id), Ok(Address::ZERO));
+        assert_eq!(contract.get_approved(U256::from(2)), Err(b"Token does not exist".to_vec()));
+    }
+
+    #[test]
+    fn operator_flag_is_granted_revoked_and_honored_by_transfer_from() {
+        let (vm, mut contract, circuit) = setup();
+        let token_id = mint(&vm, &mut contract, &circuit, alice(), 1).unwrap();
+
+        assert_eq!(contract.set_approval_for_all(bob(), true), Ok(()));
+        assert!(contract.is_approved_for_all(alice(), bob()));
+        assert!(emitted(&vm, &ApprovalForAll { owner: alice(), operator: bob(), approved: true }));
+
+        assert_eq!(contract.set_approval_for_all(bob(), false), Ok(()));
+        assert!(!contract.is_approved_for_all(alice(), bob()));
+        vm.set_sender(bob());
+        assert_eq!(
+            contract.transfer_from(alice(), carol(), token_id),
+            Err(b"Caller is not owner nor approved".to_vec())
+        );
+
+        vm.set_sender(alice());
+        contract.set_approval_for_all(bob(), true).unwrap();
+        vm.set_sender(bob());
+        assert_eq!(contract.transfer_from(alice(), carol(), token_id), Ok(()));
+        assert_eq!(contract.owner_of(token_id), Ok(carol()));
+    }
+
+    #[test]
+    fn set_approval_for_all_rejects_self_as_operator() {
+        let (vm, mut contract, _circuit) = setup();
+        vm.set_sender(alice());
+        assert_eq!(contract.set_approval_for_all(alice(), true), Err(b"Cannot approve self as operator".to_vec()));
+        assert!(!contract.is_approved_for_all(alice(), alice()));
+    }
+
+    #[test]
+    fn transfer_from_by_owner_moves_token() {
+        let (vm, mut contract, circuit) = setup();
+        let token_id = mint(&vm, &mut contract, &circuit, alice(), 1).unwrap();
+
+        assert_eq!(contract.transfer_from(alice(), bob(), token_id), Ok(()));
+        assert_eq!(contract.owner_of(token_id), Ok(bob()));
+        assert_eq!(contract.balance_of(alice()), U256::ZERO);
+        assert_eq!(contract.balance_of(bob()), U256::from(1));
+        assert!(emitted(&vm, &Transfer { from: alice(), to: bob(), tokenId: token_id }));
+    }
+
+    #[test]
+    fn transfer_from_by_approved_spender_clears_approval() {
+        let (vm, mut contract, circuit) = setup();
+        let token_id = mint(&vm, &mut contract, &circuit, alice(), 1).unwrap();
+        contract.approve(bob(), token_id).unwrap();
+
+        vm.set_sender(bob());
+        assert_eq!(contract.transfer_from(alice(), carol(), token_id), Ok(()));
+        assert_eq!(contract.owner_of(token_id), Ok(carol()));
+        assert_eq!(contract.get_approved(token_id), Ok(Address::ZERO));
+    }
+
+    #[test]
+    fn transfer_from_by_operator_moves_token() {
+        let (vm, mut contract, circuit) = setup();
+        let token_id = mint(&vm, &mut contract, &circuit, alice(), 1).unwrap();
+        contract.set_approval_for_all(bob(), true).unwrap();
+
+        vm.set_sender(bob());
+        assert_eq!(contract.transfer_from(alice(), bob(), token_id), Ok(()));
+        assert_eq!(contract.owner_of(token_id), Ok(bob()));
+    }
+
+    #[test]
+    fn transfer_from_rejects_unauthorized_caller() {
+        let (vm, mut contract, circuit) = setup();
+        let token_id = mint(&vm, &mut contract, &circuit, alice(), 1).unwrap();
+
+        vm.set_sender(bob());
+        assert_eq!(
+            contract.transfer_from(alice(), bob(), token_id),
+            Err(b"Caller is not owner nor approved".to_vec())
+        );
+        assert_eq!(contract.owner_of(token_id), Ok(alice()));
+
+        vm.set_sender(alice());
+        assert_eq!(contract.transfer_from(alice(), Address::ZERO, token_id), Err(b"Cannot transfer to zero address".to_vec()));
+        assert_eq!(contract.transfer_from(alice(), bob(), U256::from(2)), Err(b"Token does not exist".to_vec()));
+    }
+
+    // ========================================================================
+    // MULTI-SCALAR MULTIPLICATION
+    // ========================================================================
+
+    // msm over (scalar, dlog) terms against the naive ec_mul then ec_add chain
+    fn assert_msm_matches_chain(terms: &[(u64, u64)]) {
+        let vm = TestVM::default();
+        let terms: Vec<(U256, U256)> = terms.iter().map(|(scalar, dlog)| (U256::from(*scalar), U256::from(*dlog))).collect();
+        let expected = mock_msm(&vm, &terms);
+
+        let pairs: Vec<(Scalar, G1Point)> = terms.iter().map(|(scalar, dlog)| (scalar.to_be_bytes::<32>(), g1_mul(*dlog))).collect();
+        let mut chain = [0u8; 64];
+        for (scalar, point) in &pairs {
+            let term = PrecompileBackend::ec_mul(&vm, scalar, point).unwrap();
+            chain = PrecompileBackend::ec_add(&vm, &chain, &term).unwrap();
+        }
+
+        let msm = PrecompileBackend::msm(&vm, &pairs).unwrap();
+        assert!(PrecompileBackend::g1_eq(&msm, &chain));
+        assert!(PrecompileBackend::g1_eq(&msm, &g1_mul(expected)));
+    }
+
+    #[test]
+    fn msm_matches_manual_chain_for_three_pairs() {
+        assert_msm_matches_chain(&[(2, 5), (0, 7), (3, 11)]);
+    }
+
+    #[test]
+    fn msm_matches_manual_chain_for_six_pairs() {
+        assert_msm_matches_chain(&[(9, 2), (4, 3), (0, 5), (6, 7), (1, 11), (8, 13)]);
+    }
+}
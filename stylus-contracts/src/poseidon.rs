@@ -0,0 +1,794 @@
+// Poseidon hash over the BN254 scalar field, compatible with circomlib's Poseidon(n)
+// (x^5 S-box, 8 full rounds, width t = n + 1, zero initial capacity element).
+// Round constants and MDS matrices are the circomlib parameters, generated with the
+// reference generate_parameters_grain.sage script from the Poseidon paper.
+// Only the widths used by the contract are embedded to keep the WASM small.
+
+use alloy_primitives::U256;
+
+use crate::BN254_SCALAR_MODULUS;
+
+const FULL_ROUNDS: usize = 8;
+
+/// Poseidon(inputs) as computed by circomlib / poseidon-lite
+pub fn hash(inputs: &[U256]) -> Result<U256, &'static str> {
+    let (ark, mds, partial_rounds): (&[U256], &[U256], usize) = match inputs.len() {
+        1 => (&T2_ARK, &T2_MDS, T2_PARTIAL_ROUNDS),
+        6 => (&T7_ARK, &T7_MDS, T7_PARTIAL_ROUNDS),
+        _ => return Err("Unsupported number of Poseidon inputs"),
+    };
+    let r = BN254_SCALAR_MODULUS;
+    let width = inputs.len() + 1;
+
+    let mut state = Vec::with_capacity(width);
+    state.push(U256::ZERO);
+    for input in inputs {
+        if *input >= r {
+            return Err("Poseidon input exceeds field modulus");
+        }
+        state.push(*input);
+    }
+
+    let half_full = FULL_ROUNDS / 2;
+    for round in 0..FULL_ROUNDS + partial_rounds {
+        // Add round constants
+        for (i, element) in state.iter_mut().enumerate() {
+            *element = element.add_mod(ark[round * width + i], r);
+        }
+
+        // S-box: every element in full rounds, only the first one in partial rounds
+        if round < half_full || round >= half_full + partial_rounds {
+            for element in state.iter_mut() {
+                *element = sbox(*element);
+            }
+        } else {
+            state[0] = sbox(state[0]);
+        }
+
+        // Mix with the MDS matrix
+        let mut mixed = Vec::with_capacity(width);
+        for i in 0..width {
+            let mut acc = U256::ZERO;
+            for (j, element) in state.iter().enumerate() {
+                acc = acc.add_mod(element.mul_mod(mds[i * width + j], r), r);
+            }
+            mixed.push(acc);
+        }
+        state = mixed;
+    }
+
+    Ok(state[0])
+}
+
+fn sbox(x: U256) -> U256 {
+    let r = BN254_SCALAR_MODULUS;
+    let x2 = x.mul_mod(x, r);
+    let x4 = x2.mul_mod(x2, r);
+    x4.mul_mod(x, r)
+}
+
+// t = 2 (1 inputs): 56 partial rounds
+const T2_PARTIAL_ROUNDS: usize = 56;
+const T2_ARK: [U256; 128] = [
+    U256::from_limbs([0x6c7dc0dbd0abd7a7, 0xa71aa177534cdd1b, 0xfe1faaba294cba38, 0x09c46e9ec68e9bd4]),
+    U256::from_limbs([0x3c1d83ffa604cb81, 0xc5142b3ae405b834, 0x2a97ed937f3135cf, 0x0c0356530896eec4]),
+    U256::from_limbs([0x317ea977cc154a30, 0xa00ea5aabd6268bd, 0x142e51182bb54cf4, 0x1e28a1d935698ad1]),
+    U256::from_limbs([0x4cf9e2b12b91251f, 0x0e5757c3e008db96, 0x080965db30e298e4, 0x27af2d831a9d2748]),
+    U256::from_limbs([0x79aaf43545b74e03, 0x41291462f214cd08, 0x3a6a3cfe16ae175a, 0x1e6f11ce60fc8f51]),
+    U256::from_limbs([0xf719206268d142d3, 0x04462ed14c3613d8, 0x8541819cb681f0be, 0x2a67384d3bbd5e43]),
+    U256::from_limbs([0x36408f5d5c9f45d0, 0xb985e381f0251889, 0x1609f8e12fbfecf0, 0x0b66fdf356093a61]),
+    U256::from_limbs([0xdaa6852dbdb09e21, 0x0b26c83cc5cebeed, 0x830c61093c2ade37, 0x012ee3ec1e78d470]),
+    U256::from_limbs([0x2d108e7b445bb1b9, 0x6cd1c431b099b6bb, 0xfd88f67f8175e3fd, 0x0252ba5f6760bfbd]),
+    U256::from_limbs([0xef5aeaad7ca932f1, 0x54391a8935ff71d6, 0x6c6bec3cef542963, 0x179474cceca5ff67]),
+    U256::from_limbs([0x7e1a2589bbed2b91, 0x9c1f974a264969b3, 0x9228ff4a503fd4ed, 0x2c24261379a51bfa]),
+    U256::from_limbs([0x53e66c0551801b05, 0xc2f63f5001fc0fc5, 0xac2f288bd0695b43, 0x1cc1d7b62692e63e]),
+    U256::from_limbs([0x5d9eff5fd9c91b56, 0x00784dbf17fbacd0, 0xb2ed55f852979e96, 0x255059301aada98b]),
+    U256::from_limbs([0xb11c29ce7e59efd9, 0xaea24234970a8193, 0x79e1f5c0eccd32b3, 0x28437be3ac1cb2e4]),
+    U256::from_limbs([0x338762c37f5f2043, 0x18548da8fb4f78d4, 0x1ca4fa6b53766eb1, 0x28216a442f2e1f71]),
+    U256::from_limbs([0x131f2377323482c9, 0xeee1efce03094581, 0x1f39f4e7056dd03f, 0x2c1f47cd17fa5adf]),
+    U256::from_limbs([0x646b8566a621afc9, 0xd9dafca276638a63, 0x8632bcc9356ceb7d, 0x07abad02b7a5ebc4]),
+    U256::from_limbs([0x37da0c4d15f96c3c, 0x9429f90880a69cd1, 0x275b33ffaab51dfe, 0x0230264601ffdf29]),
+    U256::from_limbs([0x717e5d66899aa0a9, 0xa864414557ee289e, 0xa0f168656497ca40, 0x1bc973054e51d905]),
+    U256::from_limbs([0x2a6b22288f0a67fc, 0xd249aff5c2d8421f, 0x206c3157e86341ed, 0x2e1c22f964435008]),
+    U256::from_limbs([0xa70452bc2bba86b8, 0x9e8ea1598e46c9f7, 0x121c1d5f461bbc50, 0x1224f38df67c5378]),
+    U256::from_limbs([0x69d2989186cde20e, 0xd7bfe8cd9dfeda19, 0x9280b4bd9ed0068f, 0x02e4e69d8ba59e51]),
+    U256::from_limbs([0x6d47e9735d98018e, 0x4f19ee364e653f07, 0x7f5df81fc04ff3ee, 0x1f1eccc34aaba013]),
+    U256::from_limbs([0xeacb8a4d4284f582, 0x1424448032cd1819, 0x74266c3039a9a731, 0x1672ad3d709a3539]),
+    U256::from_limbs([0x1d2ed602df8c8fc7, 0xcda6961f284d2499, 0x56f44af5192b4ae9, 0x283e3fdc2c6e420c]),
+    U256::from_limbs([0x614fbd69ff394bcc, 0x683751f8fdff59d6, 0xd0db0957170fa013, 0x1c2a3d120c550ecf]),
+    U256::from_limbs([0x96cb6b8177653fbd, 0x143a9a43773ea6f2, 0xf7897a7323456efe, 0x216f84877aac6172]),
+    U256::from_limbs([0x11a1f51552f94788, 0xceaa47ea61ca59a4, 0x64ba7e8e3e28d12b, 0x2c0d272becf2a757]),
+    U256::from_limbs([0xcb4a6c3d89546f43, 0x170a5480abe0508f, 0x484ee7a74c454e9f, 0x16e34299865c0e28]),
+    U256::from_limbs([0x48cd939755488fc5, 0x772047765802290f, 0x375a232a6fb9cc71, 0x175ceba599e96f5b]),
+    U256::from_limbs([0xd8c5ffbb44a1ee32, 0x6aa410bfbc354f54, 0xfead9e1758b02806, 0x0c7594440dc48c16]),
+    U256::from_limbs([0x92479882d919fd8d, 0x760e20013ccf912c, 0xc466db7d7eb6fd8f, 0x1a3c29bc39f21bb5]),
+    U256::from_limbs([0x95c8eeabcd22e68f, 0x0855d349074f5a66, 0xc0986ea049b25340, 0x0ccfdd906f3426e5]),
+    U256::from_limbs([0xe0e699b67dd9e796, 0x66a7a8a3fd065b3c, 0x2bdb475ce6c94118, 0x14f6bc81d9f186f6]),
+    U256::from_limbs([0x88edeb7386b97052, 0xcc099810c9c495c8, 0x9702ca70b2f6c5aa, 0x0962b82789fb3d12]),
+    U256::from_limbs([0xafef0c8f6a31a86d, 0x13284ab01ef02575, 0xbf20c79de25127bc, 0x1a880af7074d18b3]),
+    U256::from_limbs([0x4c3012bb7ae9311b, 0x20af2924fc20ff3f, 0xcd5e77f0211c154b, 0x10cba18419a6a332]),
+    U256::from_limbs([0x756a2849f302f10d, 0xfa27b7319cae3406, 0xbdc76ba63a9eaca8, 0x057e62a9a8f89b3e]),
+    U256::from_limbs([0xafa0413b44280cee, 0xb961303bbf65cff5, 0xd44adf5384b4988c, 0x287c971de91dc0ab]),
+    U256::from_limbs([0x6f7f7960e306891d, 0x1e562bc46d4aba4e, 0xb3bca9da0cca908f, 0x21df3388af1687bb]),
+    U256::from_limbs([0x3eff8b560e1682b3, 0x789df8f70b498fd8, 0x3e25cc974d0934cd, 0x1be5c887d25bce70]),
+    U256::from_limbs([0x48d59c2706a0d5c1, 0xd2cb5d42fda5acea, 0x68117175cea2cd0d, 0x268da36f76e568fb]),
+    U256::from_limbs([0xbd06460cc26a5ed6, 0xc5d8bb74135ebd05, 0xc609beaf5510ecec, 0x0e17ab091f6eae50]),
+    U256::from_limbs([0x040f5caa1f62af40, 0x91ef62d8cf83d270, 0x7aee535ab074a430, 0x04d727e728ffa0a6]),
+    U256::from_limbs([0x2b15417d7e39ca6e, 0x33702ac10f1bfd86, 0x81b549762bc022ed, 0x0ddbd7bf9c293415]),
+    U256::from_limbs([0x8a29c49c8789654b, 0x34f5b0d1d3af9b58, 0x768162e82989c6c2, 0x2790eb3351621752]),
+    U256::from_limbs([0x84b764206142f9e9, 0x395f3d9ab8b2fd09, 0x4471950193d8a570, 0x1e457c601a63b73e]),
+    U256::from_limbs([0xc4c686fc46e091b0, 0xfa90ecd0c43ff91f, 0x638d6ab2bbe7135f, 0x21ae64301dca9625]),
+    U256::from_limbs([0x5858534eed8d350b, 0x854be9e3432e0955, 0x4da293166f494928, 0x0379f63c8ce3468d]),
+    U256::from_limbs([0x8c9f58a324c35049, 0xca0e4921a46686ac, 0x6a744a080809e054, 0x002d56420359d026]),
+    U256::from_limbs([0x0fc2c5af963515a6, 0xda8d62459e21f409, 0x1d68b3cd32e10bbe, 0x123158e5965b5d9b]),
+    U256::from_limbs([0x60c80eb49cad9ec1, 0x0fbb2b6f52836d4e, 0x661d14bbf6cbe042, 0x0be29fc40847a941]),
+    U256::from_limbs([0x233802f24fdf4c1a, 0x36db9d859cad5f9a, 0x57716142015a453c, 0x1ac96991dec2bb05]),
+    U256::from_limbs([0x51ca3355bcb0627e, 0x5e12c9fa97f18a92, 0x5f4964fc61d23b3e, 0x1596443f763dbcc2]),
+    U256::from_limbs([0xd6d049eae3ba3212, 0xf1857d9f17e715ae, 0x6b2861d4ec3aeae0, 0x12e0bcd3654bdfa7]),
+    U256::from_limbs([0x04e6c76c7cf964ba, 0xceabac7f37154b19, 0x9ea73d4af9af2a50, 0x0fc92b4f1bbea82b]),
+    U256::from_limbs([0x9c7e965233872762, 0xb14f7c7722236f4f, 0xd6f2e592a8013f40, 0x1f9c0b1610446442]),
+    U256::from_limbs([0x8d159f643dbbf4d3, 0x050d914da38b4c05, 0xf8cde06157a782f4, 0x0ebd74244ae72675]),
+    U256::from_limbs([0x7a839839dccfc6d1, 0x3b0671e97346ee39, 0x69a9fafd4ab951c0, 0x2cb7f0ed39e16e9f]),
+    U256::from_limbs([0x90c72bca7352d9bf, 0xce761d0514ce5266, 0x5605443ee41bab20, 0x1a9d6e2ecff022cc]),
+    U256::from_limbs([0x87da182d648ec72f, 0xd0c13326a9a7ba30, 0x5ea83c3bc44a9331, 0x2a115439607f335a]),
+    U256::from_limbs([0x9535c115c5a4c060, 0xe738b56305cd44f2, 0x15b8fa7aee3e3410, 0x23f9b6529b5d040d]),
+    U256::from_limbs([0x260eb939f0e6e8a7, 0xa3ce97c16d58b68b, 0x249ac6ba484bb9c3, 0x05872c16db0f72a2]),
+    U256::from_limbs([0x2b624a7cdeddf6a7, 0x0219b6151d55b5c5, 0xca20fb80118075f4, 0x1300bdee08bb7824]),
+    U256::from_limbs([0x072e4e7b7d52b376, 0x8d7ad29916d98cb1, 0xe63817863a8f6c28, 0x19b9b63d2f108e17]),
+    U256::from_limbs([0x24a20128481b4f7f, 0x13d1c88726b5ec42, 0xb5bda237668522f6, 0x015bee1357e3c015]),
+    U256::from_limbs([0xea92c785b128ffd1, 0xfe1e1ce4bab218cb, 0x1b9707a4f1615e4e, 0x2953736e94bb6b9f]),
+    U256::from_limbs([0x4ce7266ed6608dfc, 0x851b98d372b45f54, 0x862f806180c0385f, 0x0b069353ba091618]),
+    U256::from_limbs([0x4f588ac97d81f429, 0x55aeb7eb9306b64e, 0x15e4e0bcfb93817e, 0x304f74d461ccc131]),
+    U256::from_limbs([0xb8ee5415cde913fc, 0xaad2a164a4617a4c, 0xe8a33f5e77dfe4f5, 0x15bbf146ce9bca09]),
+    U256::from_limbs([0xa9ff23859572c8c6, 0x9b8f4b850405c10c, 0x44901031487964ed, 0x0ab4dfe0c2742cde]),
+    U256::from_limbs([0x251de39f9639779a, 0xef5eedfea546dea9, 0x97f45f7649a19675, 0x0e32db320a044e31]),
+    U256::from_limbs([0xa3078efa516da016, 0x6797733a82774896, 0xb27635a78b6888e6, 0x0a1756aa1f378ca4]),
+    U256::from_limbs([0x4254d6a2a25d93ef, 0x95e61d328f85efa9, 0x47fd17177f952ef8, 0x044c4a33b10f6934]),
+    U256::from_limbs([0xd37b07b5466c4b8b, 0xfe0879d79a496891, 0xbe655b537f66f700, 0x2ed3611b725b8a70]),
+    U256::from_limbs([0xd8339ea7120858aa, 0xadfdeb9cfdd347b5, 0xc8ecc3d722aa2e0e, 0x1f9ba4e8bab7ce42]),
+    U256::from_limbs([0xb74056f865c5d3da, 0xa38e82ac4502066d, 0x8f7ee907a84e518a, 0x1b233043052e8c28]),
+    U256::from_limbs([0xca2f97b020875954, 0x902053bfc0f14db0, 0x74031ab72bd55b4c, 0x2431e1cc164bb8d0]),
+    U256::from_limbs([0xa791f273965801fd, 0xa13e322097583319, 0x30cd6953a0a7db45, 0x082f934c91f5aac3]),
+    U256::from_limbs([0x9ad6bb930c48997c, 0xc77245e2ae7cbe99, 0xa34be074315542a3, 0x2b9a0a223e7538b0]),
+    U256::from_limbs([0xb0b589cc70214e7d, 0x8164163e75a8a00e, 0xceb85483b887a9be, 0x0e1cd91edd2cfa2c]),
+    U256::from_limbs([0x88d324601cebe2f9, 0x99774f19854d00f5, 0xc951f61477e36989, 0x2e1eac0f2bfdfd63]),
+    U256::from_limbs([0x23d748115b500b83, 0x7345784d8efdb33c, 0x0c76158e769d6d15, 0x0cbfa95f37fb7406]),
+    U256::from_limbs([0x980c232dfa4a4f84, 0x76d991e3a77513d9, 0xd65ad49d8a61e9a6, 0x08f05b3be923ed44]),
+    U256::from_limbs([0x25a2dd510c047ef6, 0xe7284925dc0758a3, 0x52bf8e21984d0443, 0x22719e2a070bcd08]),
+    U256::from_limbs([0xf41f62b2f26830c0, 0x7bdbf036119982c0, 0xc060f7fcc3a1ab4c, 0x041f596a9ee1cb2b]),
+    U256::from_limbs([0x19fcdd0986b10f89, 0x021be1c2d0dc464a, 0x87628eb06f6b1d4c, 0x233fd35de1be520a]),
+    U256::from_limbs([0xefcb453c61c9c267, 0xd31e078aa1b4707e, 0x4325e0a423ebc810, 0x0524b46d1aa87a5e]),
+    U256::from_limbs([0xcc4486237c515211, 0x4227bb954b0f3199, 0xce47fcac894b8582, 0x2c34f424c81e5716]),
+    U256::from_limbs([0xf33010327de4915e, 0x2dd2025b5457cc97, 0x207effc2b5541fb7, 0x0b5f2a4b63387819]),
+    U256::from_limbs([0xaefac41fe05c659f, 0xc17435d2f57af6ce, 0xc5b72fe439d2cfd6, 0x22207856082ccc54]),
+    U256::from_limbs([0x27854048ce2c8171, 0xcdfb210194caf79f, 0x4e24159b7f8950b5, 0x24d57a8bf5da63fe]),
+    U256::from_limbs([0x73919bb23b79396e, 0x374ad7097bb01a85, 0x3b371d75bd693f98, 0x0afab181fdd5e058]),
+    U256::from_limbs([0xf16290d62b1128ee, 0x76c0057194c16c0b, 0x998a52efac7cbd56, 0x2dba9b108f208772]),
+    U256::from_limbs([0x5aff13e6bce420b3, 0xcbb83de0bd592b25, 0x56f881c788f53f83, 0x26349b66edb8b16f]),
+    U256::from_limbs([0x235288a3e6f137db, 0xd81a56d28ecc193b, 0x685e95f92339753a, 0x25af7ce0e5e10357]),
+    U256::from_limbs([0x1f7c0187fe35011f, 0x70eed7aae88b2bff, 0xc094d6a55edd68b9, 0x25b4ce7bd2294390]),
+    U256::from_limbs([0x8cb9d54c1e02b631, 0xde9cef28ebdf30b1, 0x387e53f1908a88e5, 0x22c543f10f6c89ec]),
+    U256::from_limbs([0xdf668e74882f87a9, 0x425e906a919d7a34, 0x4fc7908a9f191e1e, 0x0236f93e7789c472]),
+    U256::from_limbs([0x9cb497af980c4b52, 0x652bdae114eb0165, 0x0e7d27e37d05da99, 0x29350b401166ca01]),
+    U256::from_limbs([0xee1260916652363f, 0x65edb75d844ebb89, 0x6bd31bbab547f75a, 0x0eed787d65820d3f]),
+    U256::from_limbs([0x1906f656f4de6fad, 0xfdcd0e99bd94297d, 0x036a753f520b3291, 0x07cc1170f13b46f2]),
+    U256::from_limbs([0x2059435689e8acea, 0x908786d7f9f5d10c, 0xf49bcf613a3d30b1, 0x22b939233b1d7205]),
+    U256::from_limbs([0xadd650ace60ae5a6, 0x740f083a5aa85438, 0x8aad1dc8bc33e870, 0x01451762a0aab81c]),
+    U256::from_limbs([0xe704fec0892fce89, 0xe32eaa61dec7da57, 0x61fabf1025d46d1f, 0x23506bb5d8727d44]),
+    U256::from_limbs([0x7f8bd68907355522, 0x2a3709531e1efea9, 0xbac06ae3f71bdd09, 0x2e484c44e838aea0]),
+    U256::from_limbs([0x45418da26835b54c, 0xaf4a594545cedc25, 0x379e78c50bd2e42b, 0x0f4bc7d07ebafd64]),
+    U256::from_limbs([0xe620996d50d8e74e, 0x51582388725df460, 0xfa76637862faaee8, 0x1f4d3c8f6583e9e5]),
+    U256::from_limbs([0x53eb9bcb48fe7389, 0xfae02abc7b681d91, 0x2660d07be0e4a988, 0x093514e0c70711f8]),
+    U256::from_limbs([0x4a58e0a347e153d8, 0x43ee83ece47228f2, 0x46699a2b5f3bc036, 0x1adab0c8e2b3bad3]),
+    U256::from_limbs([0x1a22dbef9e80dad2, 0x378c1b94b8072bac, 0xd14709ebb474641a, 0x1672b1726057d99d]),
+    U256::from_limbs([0x30d47b239b479c14, 0xc5d8e2fae0acc4ee, 0x8f44f53fdcab468c, 0x1dfd53d4576af2e3]),
+    U256::from_limbs([0xbc7f207753205c60, 0xe6d77d640f6fc3de, 0xa70a36263a37e17f, 0x0c6888a10b75b0f3]),
+    U256::from_limbs([0x85091ecca9d1e508, 0x611a61e00ee6848b, 0x92b34a7e77d12fe8, 0x1addb933a65be770]),
+    U256::from_limbs([0x7935628e299d1791, 0xf638ff5425f0afff, 0x5c10ae18d1de933c, 0x00d7540dcd268a84]),
+    U256::from_limbs([0xd316939d20b82c0e, 0x26fedde4acd99db1, 0x01b2827a5664ca9c, 0x140c0e42687e9ead]),
+    U256::from_limbs([0xc091e2ae56565984, 0xc20a0f9b24f8c5ed, 0x91ba89b8d13d1806, 0x2f0c3a115d4317d1]),
+    U256::from_limbs([0xd8c538a1dc958c61, 0x08a0cff670b22b82, 0x3006ed220cf9c810, 0x0c4ee778ff7c1455]),
+    U256::from_limbs([0x27c3d7485de74c69, 0x9424ed26c0acc662, 0x3693f00440ccc360, 0x1704f2766d46f82c]),
+    U256::from_limbs([0x39b66fe9009c3cfa, 0xf0769c9f8544e402, 0xa7a02c1b51d244ab, 0x2f2d19cc3ea5d78e]),
+    U256::from_limbs([0xd6c766a806fc6629, 0xdd7ee6cb9cfed9c7, 0x5053f112e2a8e8dc, 0x1ae03853b75fcaba]),
+    U256::from_limbs([0x4e41a86ddaf056d5, 0x3556921b2d6f014e, 0x51d131d0fa61aa5f, 0x0971aabf795241df]),
+    U256::from_limbs([0x5f5c29f7bfe2f646, 0xda624f8380df1c87, 0x91d4cf6b6e0de73e, 0x1408c316e6014e1a]),
+    U256::from_limbs([0x41691f39822ef5bd, 0x6c89f1f773ef2853, 0x248abe42b543093b, 0x1667f3fe2edbe850]),
+    U256::from_limbs([0x424c69576500fe37, 0x5b81718409e5c133, 0xa48b0a03557cdf91, 0x13bf7c5d0d2c4376]),
+    U256::from_limbs([0x19bc0ba743a62c2c, 0x024b95347856b797, 0x3016adf3d3533c24, 0x07620a6dfb0b6cec]),
+    U256::from_limbs([0x1675de3e1982b4d0, 0x75d2959e2f322b73, 0x36a8ca08bdbdd8b0, 0x1574c7ef0c43545f]),
+    U256::from_limbs([0xc06e03a7ff8378f0, 0x5bd4184571c254fd, 0xfd567970a717ceec, 0x269e4b5b7a2eb21a]),
+];
+const T2_MDS: [U256; 4] = [
+    U256::from_limbs([0xbceca70bd2af7ad5, 0xaf07f38af8c952a7, 0xec10345351a23a3a, 0x066f6f85d6f68a85]),
+    U256::from_limbs([0x05462b9f8125b1e8, 0x20a7c02bbd8bea73, 0x7782e1509b1d0fdb, 0x2b9d4b4110c9ae99]),
+    U256::from_limbs([0xf573f431221f8ff9, 0xb6c09d557013fff1, 0x2bf67a4493cc262f, 0x0cc57cdbb08507d6]),
+    U256::from_limbs([0x21bcd147943203c8, 0xade857e86eb5c3a1, 0xa31a6ed69724e1ad, 0x1274e649a32ed355]),
+];
+
+// t = 7 (6 inputs): 63 partial rounds
+const T7_PARTIAL_ROUNDS: usize = 63;
+const T7_ARK: [U256; 497] = [
+    U256::from_limbs([0x2ff84824743fe4e7, 0xd14e554db66d0910, 0x7c6dbf46c4ad93e7, 0x2197703fceb4cbf0]),
+    U256::from_limbs([0xaca2fd3efe512f79, 0x1f59bd5061d8b565, 0xbd8b5fc23e7106b5, 0x1322f0fffc7e4854]),
+    U256::from_limbs([0xe46f448fa9d61359, 0xa8c5894c2f88861e, 0x222133118c337d82, 0x19e414f80380aa2d]),
+    U256::from_limbs([0xcbfdd6d7b9209d9a, 0x65f16460e79a7939, 0xd65249d9562cad6c, 0x22241199a4df9cb7]),
+    U256::from_limbs([0x1c77f6d85e8a41b6, 0x7b51c78b211615f4, 0xecf9a311c22ade32, 0x0301061735384dfc]),
+    U256::from_limbs([0x379548ff2b6850ee, 0xb7dc5d19ac95fd8d, 0x4a2633b7b4b5bb61, 0x229e1d86c1f2810f]),
+    U256::from_limbs([0xfdc28ac3dfbea675, 0x3f56bff65883afe5, 0x17eeb292e989727c, 0x0e31dcf9c53209a8]),
+    U256::from_limbs([0x7e8c29a358b40f2a, 0x6b8eb25272eb8840, 0xc9d79ac11237263c, 0x222c7318ebe96306]),
+    U256::from_limbs([0x3ff4e3604974a2c6, 0xd12593394cd52905, 0xcf4fc823be8f4fed, 0x0cb416b42c53a829]),
+    U256::from_limbs([0xd2d2c2aaddb6c0aa, 0x99dee612842b6db3, 0x8b1adfbf2e2ed753, 0x0892729d09594a8d]),
+    U256::from_limbs([0xa0e334dee2d27c71, 0x24375d9f482a4487, 0x97a15bd672711ae1, 0x18e39e9ae45f14ba]),
+    U256::from_limbs([0xd4d7ac649edd6610, 0x0461d18eaf0f8992, 0x9dbb33844d0d2287, 0x2560c9ff672e6ea6]),
+    U256::from_limbs([0xe5f963e814c57312, 0xda7ccf1e487f9916, 0xef17f77366b1dcfb, 0x21bee2b8ed54b726]),
+    U256::from_limbs([0xe82fec147cb070ba, 0xe4eda8425dd0c7c6, 0xd4bdbc19be29a3de, 0x300e1b28dabebdc0]),
+    U256::from_limbs([0xa732d2aeae88cc23, 0x82b9405f9fe38de4, 0xdd2961d67011af49, 0x2c44a5ff45deea69]),
+    U256::from_limbs([0xac5ec64108291bfd, 0xc93d876ba3234dd1, 0x5e6aaad9e3b4e7b1, 0x0c8d59fe1b7f5509]),
+    U256::from_limbs([0x59fa53103fcd78b5, 0xcca1660446ba0497, 0x48a30b0c45378d90, 0x06cf82bc5fd115d3]),
+    U256::from_limbs([0x829293347dc9061c, 0x0efdf95d8abeaee8, 0xe2145111c3fe6ca7, 0x2f80ed01a2118d02]),
+    U256::from_limbs([0xda67df08dded9e42, 0xb8414e3d651dd12f, 0x7b026e079075908c, 0x0bdfe7f6803e4dee]),
+    U256::from_limbs([0xf17c19ad622e29b1, 0xb31a963a0dba66ae, 0x5b34d39138f50bb5, 0x06cc48ed88240559]),
+    U256::from_limbs([0x518258bcc6867c95, 0x3801bf16a50336a4, 0x32ac5622cf9e2b49, 0x2a67f3ba6d159499]),
+    U256::from_limbs([0xd833c4b14fef412a, 0x80c8f395aeb6a20c, 0xdf656934aa78fbeb, 0x2572725a67c60718]),
+    U256::from_limbs([0x9b79685d1df0bfa7, 0x6579cb753330b187, 0x1f19721265b41616, 0x03bb7f6ca43545b1]),
+    U256::from_limbs([0x1b8eb3156ed5c12b, 0x393300b25133e922, 0xc28bfcbb957faa5f, 0x2412815e863f8f3b]),
+    U256::from_limbs([0xbdcd0630f433af45, 0xdb09a93e5bc305ce, 0x307871966de097a5, 0x0dbf64d58df212e4]),
+    U256::from_limbs([0x86273f810f15c6ab, 0xb190529df8f50b63, 0xff4a791e25b7bb3d, 0x274c06c01158e047]),
+    U256::from_limbs([0xd0fe3991ee5fd33d, 0x2111d0abecfc18ac, 0xed3d842f7bc85d1a, 0x23cf40693f52abf0]),
+    U256::from_limbs([0xdcc4ee63c4099698, 0x3ff8daefbcf900e6, 0xf4e4cf924166b8bd, 0x1daaa926528f3368]),
+    U256::from_limbs([0x0de3ea2817a01555, 0x9bd92fd20ee1a32d, 0x718657bebb6781e3, 0x10103789dc9471ad]),
+    U256::from_limbs([0x5a4660a4d9b6d607, 0xd1085f19e9ffc896, 0xc6e9932e34bfde78, 0x0009bc25065080e6]),
+    U256::from_limbs([0xece466e9dcd6afee, 0xab31a2999b377dab, 0x174e1dcb82588edf, 0x1312c727cb5489bb]),
+    U256::from_limbs([0x15f00f7cb9a7009f, 0x16682ae3d726824f, 0xaeb5374618e5e773, 0x20294a462c7d0c25]),
+    U256::from_limbs([0x7a05513ad7a23e91, 0x0e9d73fdb06259f4, 0xd8391c90cf35588f, 0x25e0c6be89bbb04b]),
+    U256::from_limbs([0xb9ec287f28810fd3, 0x38be313efc420d28, 0x9a911069f89d875a, 0x0084a0b87e0b336c]),
+    U256::from_limbs([0x12f4d350d7c6f7da, 0xa1d97f5807ba2e18, 0x268f76241d54d477, 0x28a03ee29f612aab]),
+    U256::from_limbs([0x329a6f33068d7eda, 0x06ee6a4035527c4d, 0xe0b35f76bd55a7d1, 0x0b6794182b723a12]),
+    U256::from_limbs([0x32940836facea86d, 0x95b92b85122f6a69, 0xc686b79db4959c84, 0x015b9a698465428f]),
+    U256::from_limbs([0x8936fdc36f59df7b, 0xa1279bb76e8cec9a, 0x3619f14866138ae9, 0x1bfe52289f811f5c]),
+    U256::from_limbs([0x2c31bdc9af091fa3, 0x76e1d309a7e25905, 0x8b92b23a97173421, 0x1cd574fe8d5e4c42]),
+    U256::from_limbs([0x1f63afaecd38cab7, 0xbfa4f607f5da122e, 0x735bc5851a156adc, 0x212d1bbdfaaf0200]),
+    U256::from_limbs([0xabb94b13fee9edbc, 0x3788e9e1ca64ddf2, 0xd903a951f95b1309, 0x0b0479e28820e883]),
+    U256::from_limbs([0x26d8460d4fbbdaaa, 0x3c77a15cc8ee0ef7, 0x9d73b76e9ca95c51, 0x0110f5257c21c937]),
+    U256::from_limbs([0x5a50cca4f0bc6994, 0xc186b6c2838e38eb, 0x0c2487dfed34e215, 0x08c52e9401a9764f]),
+    U256::from_limbs([0x339440dc3aaeeca3, 0x44c538abccc7643f, 0xf46f4b0e5052f757, 0x2e422b4323cb4c0a]),
+    U256::from_limbs([0x573b835b05c55fe6, 0xc4a327c4bb9d78c2, 0x45549ff79cd04aeb, 0x2e12482ba160472d]),
+    U256::from_limbs([0x4267aa637a032d36, 0x02f09100eed203ae, 0xce92cfc73ea24462, 0x124fa5f9c5a96d6c]),
+    U256::from_limbs([0x723adf4135796de7, 0xe08f5e1603f933a1, 0xa037a941d4e2a4b3, 0x0bf651f82f83ca1f]),
+    U256::from_limbs([0xcb1fe4382b619463, 0xa5554c6c9f92e801, 0xcbad39b21b242ab9, 0x21c674ea7b834741]),
+    U256::from_limbs([0x1559d144a271b341, 0x193e1caf16d7f6dc, 0x83186b57fd9fa48a, 0x214f91d6c4aef57e]),
+    U256::from_limbs([0xc2d8e3f68035055b, 0x62785ec68c759599, 0x6c6f66213f2269c2, 0x203e2218389729c2]),
+    U256::from_limbs([0x6c92b2349b7eb3a2, 0xc22fc80391405ce7, 0x658f51293fad44b1, 0x1aa934198713ce17]),
+    U256::from_limbs([0xa67bbc6678e45351, 0xaf7fc56e8b799616, 0x3c2b29684476afdb, 0x1014d4e5ddd9c17b]),
+    U256::from_limbs([0x96911fb991710358, 0xf9d4e24546e20dc5, 0x47e1d2d45d7fc8fb, 0x033c13ed45b4762a]),
+    U256::from_limbs([0x7016a2d70ce71d5e, 0x3b7e435138f4f321, 0xe5c5d77c51fe69b2, 0x09bc1c3274c569e7]),
+    U256::from_limbs([0xab2a91b36b63322a, 0x99ca90137f6ab8cd, 0xe38d8308db101d6a, 0x1803171c9dbc0610]),
+    U256::from_limbs([0x4b15b084488cfdcf, 0x7bd4454c0e7da8c7, 0xfbdeb46a88e4a5d1, 0x0ba6071aa98afec3]),
+    U256::from_limbs([0x5fbd6e8ebf838e97, 0x077b710879911f21, 0xd281a8709057487e, 0x179e90ab4b08a4ea]),
+    U256::from_limbs([0x97238ea007f491a4, 0x08adbad808e65108, 0x8ff87deea978d000, 0x1cbca88b8ab3f058]),
+    U256::from_limbs([0x8831e10f7e9b9f5a, 0x7d7923ef2fa8ede0, 0xf62777de5e319837, 0x2f50b20b33692bb0]),
+    U256::from_limbs([0x48ff076d1ba68707, 0x1a1cfc1232f7ec71, 0x60566fd6fcfffcd2, 0x15ba030e8f1ef6f2]),
+    U256::from_limbs([0x516cfb9dcd543a6f, 0xb8281bb4940f97a5, 0xc0fad83a3cc966f0, 0x0dbe053da01aa4b7]),
+    U256::from_limbs([0x8b0ce6d839f6dd7e, 0xd799afb795e68d4d, 0x8997f31d6fcb94a5, 0x222eca5d754afeca]),
+    U256::from_limbs([0xa33ee2131b46a446, 0x91112618362f1b29, 0x2db7f584bd624059, 0x2abffa43d756ef24]),
+    U256::from_limbs([0x5f077bfd788eb3fd, 0xcf962d6ad41bf268, 0xd0dd0aac464f0e6e, 0x03c92524ebe67300]),
+    U256::from_limbs([0x9d885b2f5dc3341a, 0xa52209295d1a4bc8, 0xff3858305288d3b4, 0x282241758ade4ef8]),
+    U256::from_limbs([0xc2d83c1dc2817687, 0xc607fc5487e898c4, 0xd2137bd81680dd3b, 0x02635cb973d71bb3]),
+    U256::from_limbs([0xcfcf014bbea223e9, 0x91994887d35622f4, 0x079dc24c2df7b1e6, 0x13c866aa66e8d506]),
+    U256::from_limbs([0xfee6620b60e2c023, 0xe6594bb5344d2839, 0xf969f1b6f6315992, 0x151f0007ccf42a7e]),
+    U256::from_limbs([0x97cb15c92ba1409c, 0xf6e198d2b140ddcc, 0xa4dc7ef18e194274, 0x1c1c6a5ebccee8ca]),
+    U256::from_limbs([0xc7cc53dd28e45a65, 0x04b0dd459f2b254f, 0x61a996d7ed69bba9, 0x18330c9e67d62f78]),
+    U256::from_limbs([0x1822cdde5387290d, 0xbdb29a90f158933a, 0x9cb11bdf02fa63a4, 0x26b747740c95bf13]),
+    U256::from_limbs([0x7dc3772f579af8b1, 0x214f426852dcb2ec, 0x46ef9bc7234f312c, 0x1475676fb2050842]),
+    U256::from_limbs([0x32bd190767bc380d, 0xe73ee269dc032d2e, 0xaaf30bbc3200efff, 0x1b03ad9080d29b70]),
+    U256::from_limbs([0x4642eceb552f4455, 0x89577b27a1f84fa2, 0x99735bc7c201284f, 0x21c175f937c2f82e]),
+    U256::from_limbs([0x5df2cdccdd4a4e4b, 0xde91cf8c71ebda83, 0xc5887527aa4474c2, 0x1192e0269a6b66a8]),
+    U256::from_limbs([0x13bba9792a45a58d, 0x48aeb3d5121d3111, 0x4f2b4a0dcd13541b, 0x0f3a12c0ee52f0d3]),
+    U256::from_limbs([0x3b1e183c6d7eb5b7, 0x9458a061bcb87eeb, 0x33689205b45e5226, 0x07fb521883bdf78b]),
+    U256::from_limbs([0x5b8e474f64cf3169, 0x1fe648b96f95ef6f, 0x640c96c6f010c493, 0x248d0e8051c1a965]),
+    U256::from_limbs([0x8b7a3c4de4877cd6, 0xf3521875f7e90a9b, 0xce91febe180529ef, 0x2ab553db9ead2c82]),
+    U256::from_limbs([0xbab6a133846d5b5c, 0xe8444e42a72a8200, 0x54eef22a0a82c2ed, 0x211ee3bda3c95f19]),
+    U256::from_limbs([0x732f54bcdf767be4, 0x4ee65a51c2062d66, 0x2f79c129904a9cac, 0x111db3b5184ff741]),
+    U256::from_limbs([0xc630b449ec4a5f67, 0x4fa98337e5c10096, 0x1bb6fb68ef76037c, 0x1a73843264cf9a3a]),
+    U256::from_limbs([0x7da02f9144da855e, 0x44353add09c27cd0, 0x28c85358662d6a7c, 0x1c2e5a2d9332f651]),
+    U256::from_limbs([0x1a24e3625cafff98, 0x8bbdea39e2bf468a, 0xde5b67a7458fa618, 0x0e2b35fe1021b879]),
+    U256::from_limbs([0x8a9a37e32c7a28de, 0x85c4fa94ce9e87fa, 0xf272b8d6e8c99bd7, 0x2e568594e5dfbfad]),
+    U256::from_limbs([0x248e7917ddaf0c3a, 0xa4bcb58238ed6a6a, 0x205bfda42d693527, 0x07043c7571cb7dc5]),
+    U256::from_limbs([0x8da6ff7aa79efe1f, 0x378f3c691dadfb22, 0xfd6b85e96343f1a8, 0x239e18a0c85b845f]),
+    U256::from_limbs([0x780af9f5a1b676c8, 0x06600f173cb65ef0, 0x00f5ff1cf1f2bc86, 0x25e247af250c564c]),
+    U256::from_limbs([0x2c6e3eb2fd12100d, 0x3a6049b2504e9cdc, 0x3e0801f1698b1503, 0x031ba3385664544e]),
+    U256::from_limbs([0x2da5c3609431ff65, 0x9b20370cbeeccad3, 0xdfb1ca5687db1e05, 0x2a33777739e2ab0a]),
+    U256::from_limbs([0x7d05384b3e7af952, 0x903d413b2df29b77, 0x3749327b9c5cec69, 0x2bfd6f0e9e996ba1]),
+    U256::from_limbs([0xfd43eafc2cc5e823, 0x9e02e692d0748c07, 0xe36747ee92d90cad, 0x2d453d93fb36fbf8]),
+    U256::from_limbs([0x381d974ac5d5037e, 0x818880eb80f6b730, 0xa2b6bad82958d115, 0x187bd8043f8642cd]),
+    U256::from_limbs([0x7713b08649443930, 0x35c3e48ccec44121, 0x35708fe5140729fb, 0x21aca605f92a48ac]),
+    U256::from_limbs([0x8bbcc7f0fd2d1e03, 0x9df113de30aa485e, 0x6c2e99f3e2eaa637, 0x173fd164f5224ac0]),
+    U256::from_limbs([0x89b61b57d44d69bd, 0x1142d73cd1db590e, 0x292bf1e5acb6de61, 0x071c66a6463c3fd4]),
+    U256::from_limbs([0x6c666982ff72bbf8, 0xaaef6675bebe9226, 0x50d8ccce7b82ee2c, 0x044a40e396855be5]),
+    U256::from_limbs([0xfedef2e51569e4f6, 0x1ded293ffdf99599, 0xf087110a15aaf98d, 0x1176d9f52e12df67]),
+    U256::from_limbs([0xe5797e8457b63916, 0x5b67b4a671cfcdfe, 0xb15c6624bee2f37d, 0x06e8eb7dd026d257]),
+    U256::from_limbs([0xf306def38dc5673a, 0xefbb612e32c91d5e, 0xca5cf59009ab0baa, 0x0696aa48c1d5fe84]),
+    U256::from_limbs([0xac417004a3a1accc, 0x5db82d241eef9f3f, 0x355c92c75de44436, 0x0853c33a9c7a7c3b]),
+    U256::from_limbs([0x7e66e95ba25ab77f, 0x8154f5f980259171, 0x67f59cf96ce16e90, 0x3016c0ba34678e0a]),
+    U256::from_limbs([0x9a4f746542603b0e, 0xa682caa93256f2f0, 0x17f303ff9e22c793, 0x0af008601dc95525]),
+    U256::from_limbs([0xa38765f847f731b0, 0xc25fc16f8d7d1855, 0xae387cb647790318, 0x04cb6d76b5a27a5a]),
+    U256::from_limbs([0xb4f586177148565f, 0x6933d62614f266e6, 0xcad49e58d47875f2, 0x2ecf70c372d8e5ed]),
+    U256::from_limbs([0xcaa6ecc55f3dd413, 0xf8c4889742313c06, 0x4d52b0e7820a7132, 0x01f393a6ee74dd6c]),
+    U256::from_limbs([0xf8c3a73723f094db, 0x5e14721282769f7c, 0xf86b88e969d13e41, 0x2e4d4ce3f97bf96f]),
+    U256::from_limbs([0xc5776460c18c1add, 0x4ea59bf8efa0bd8b, 0xc652e3a936757855, 0x306000ef24a7f5a9]),
+    U256::from_limbs([0x8bde40617c2b3d49, 0xc43a69024ebf249c, 0xb9c225c1d3b93ae7, 0x26e58d6287678f3d]),
+    U256::from_limbs([0x2c0a0d317310655a, 0xf0b8b98ddc05b961, 0x83971df4d7ce56c8, 0x09eee09f873d1bf6]),
+    U256::from_limbs([0xcb1dd604392ec00c, 0x4cc6f4d18553b08b, 0x11ded556ef01c6f9, 0x0f08879dfd4fa0dc]),
+    U256::from_limbs([0xd5be0d5a20029eb7, 0xa25bd867171523ba, 0x7777e34d6d93ec28, 0x11459f7e9f0e3c72]),
+    U256::from_limbs([0xf7f37f4e5b0528ad, 0xf78c1f5acd227880, 0xa20fbd801a53b723, 0x04e9cbb6f6e8e805]),
+    U256::from_limbs([0xa7304f9868f7c7c6, 0xae88b3e9af5dad01, 0x6dd46bd5939c9a9a, 0x2d2ff32eb7a0c026]),
+    U256::from_limbs([0xb76fc5fdae16d839, 0x3d9b405fa2538a5c, 0x00a590447d645a72, 0x23b77eb0f27e9502]),
+    U256::from_limbs([0x7233a469e9998c2f, 0x6849886318a6c1c2, 0x2daee2067e2f958d, 0x2cd6d2c5658ec079]),
+    U256::from_limbs([0xda76861e739855e7, 0xaf83953e26eca839, 0x80d0c83026bfcc84, 0x0ae1daa420bac907]),
+    U256::from_limbs([0xd3f580a914967bf0, 0xf0e81fe32b5cb072, 0x75b68763bda6dd8e, 0x2b495b854553d02a]),
+    U256::from_limbs([0x5640b53ea959e296, 0x6c9256a4b72e12a3, 0x5735351d2d48af5d, 0x056fa5d7ce2c32bc]),
+    U256::from_limbs([0xc3c4e50da961a87b, 0x0e1a0ac7a3476c5c, 0xea7d22df11cd224a, 0x2ecbe1a06b28ec78]),
+    U256::from_limbs([0x0fe8f627579fe6cf, 0x792bffeecf02d6aa, 0x3bd677e5db6e9975, 0x143053c1c387f935]),
+    U256::from_limbs([0x182e234212d42bdb, 0x494189bc63342bb1, 0x11ea74ac047a8b37, 0x04064b25584499a0]),
+    U256::from_limbs([0xc66de8b4a069564a, 0x44c82deab5b90e6a, 0x6760a6d29af5dd86, 0x100f61f6b8154215]),
+    U256::from_limbs([0x67688ed65b51d6dd, 0x4f02b0e2a2078bff, 0xa7055c029fa77aff, 0x18d331b2e8180b7a]),
+    U256::from_limbs([0x873fac80ccef085c, 0xfb6aafde2b46207d, 0xf9b03255cf80ccb9, 0x0fcf9b89e19b1a55]),
+    U256::from_limbs([0x97f7708dbcfdc17b, 0xf231bb1f76c6c7b4, 0x36ef63df67d912c1, 0x254f801f3c89c624]),
+    U256::from_limbs([0xfd0d6ec882b9d161, 0x63c4f95afba3e4d8, 0xa61461bcd8d3d3c9, 0x16d8e85e57819a10]),
+    U256::from_limbs([0xcf408c92820ada84, 0xfb93253a8f0f04e5, 0xd443e4620d84bb2b, 0x29c9565aa702088b]),
+    U256::from_limbs([0xd47b583a60d29cf9, 0x1b5f1408624eda46, 0xeb237bd705a55787, 0x294fb68064643d8b]),
+    U256::from_limbs([0x46de7dc795197313, 0x84aa11770451e6a8, 0xbaf24c3df9223175, 0x2c26f3083140c104]),
+    U256::from_limbs([0xa7d6944c838904cb, 0x21a949a1d9671380, 0xbaa537cb27241e70, 0x231811279854707d]),
+    U256::from_limbs([0x8be9c5a2a275b495, 0xc7ad0570cc6cf363, 0x7d1bb0aa8a4b5d11, 0x1419365adbb91f1f]),
+    U256::from_limbs([0x176ea35b4e02fb84, 0xb2a29e86d451f00d, 0x6eb001feca48ddd4, 0x1674425f2e9fcaa6]),
+    U256::from_limbs([0x80d6d5bed898cd30, 0xdcc2fe1dd5a75dc1, 0x028000cef03d7b54, 0x291f956def59b878]),
+    U256::from_limbs([0x9c7057b7ef965bcc, 0x012394d78de49454, 0x5bc03fbaa69c4d95, 0x2f1302894fe6a26b]),
+    U256::from_limbs([0x9ac5d92d0a1a1ce2, 0x8de13d278a2b24c9, 0xa17db45c0796f7db, 0x0627d6d6d94d0a19]),
+    U256::from_limbs([0xf3a294f0bfbb2fc5, 0x3025515b10465d59, 0x01df8e62bd5f50f0, 0x2ee297662bafebcb]),
+    U256::from_limbs([0x879c60a1bfaba75b, 0x2eeae7d967062fdd, 0x2fef5429da766b90, 0x118029945df773f5]),
+    U256::from_limbs([0xc68b53bd4df0e858, 0x79752ffec6b8536c, 0xa789eb4323c21692, 0x306042d262f6268b]),
+    U256::from_limbs([0x2b34f8ce709aee4a, 0x5c82a0ebd9278def, 0x1132e05f9ef60913, 0x0472a13ac7da9ebe]),
+    U256::from_limbs([0x4743a970f42a130f, 0xbf96019bf0d76dbb, 0xf00c5a6c2d65850b, 0x2e8f76b27b474180]),
+    U256::from_limbs([0x0ede4b362c32aaa4, 0xb7d846a7d5ea4528, 0x4a33fee31f613544, 0x1e33984165f47c20]),
+    U256::from_limbs([0xa9fdf28524b7b43d, 0xce4fea7adfbacb2e, 0x1a72b59eb276a666, 0x17f22e27252bdcf9]),
+    U256::from_limbs([0x039b8847cff6e6c9, 0x823a0f02bc963b43, 0xb4a4d386c8baa55d, 0x00547e7c70ee4e3f]),
+    U256::from_limbs([0x52b2a99480724e25, 0x7153ab09c54a87a8, 0x5ccd15494f38f3f9, 0x08ea4ccbf815be32]),
+    U256::from_limbs([0xbe45d194c67fa8db, 0x7404df9eff5401ce, 0x673ba05d48c712fe, 0x277cb45f3e632718]),
+    U256::from_limbs([0x9df866a92dc87b37, 0x807b0d099d3c40b6, 0xa8b0a23c1dba0023, 0x0159ec7ea49aadb3]),
+    U256::from_limbs([0x8f660a86ee18524e, 0xc7fb2d89866a2841, 0x6de579c5c112ab40, 0x0c33489297e24495]),
+    U256::from_limbs([0x91151679ed37d21f, 0x7d6ddf4fe6adabb5, 0x399568c6b3849da2, 0x01bb4fcbae28a2f3]),
+    U256::from_limbs([0xdbc6d733d52305c9, 0xce4c1d5b9bf34b03, 0xb8f413f52ee1b233, 0x2a23343d24a9cfa3]),
+    U256::from_limbs([0x8fa93264016b7786, 0xa850134aacd120f3, 0x3a9b06d6be048def, 0x0707a6d515b3bad0]),
+    U256::from_limbs([0x469b62120ce35cb4, 0x8db439ffd04154ec, 0x0ba6b866184da56e, 0x1837db5401dd6ec3]),
+    U256::from_limbs([0x6c101793952f6cfb, 0x8e1409a0f0026de3, 0x47e6b6a5afeabf19, 0x244872a0d2766b85]),
+    U256::from_limbs([0xdd2ae31e84eba1f5, 0x679ca331832a59fd, 0x761196395f7da46f, 0x204fb17927ce873c]),
+    U256::from_limbs([0x9b763a12072f4c2e, 0xbf818bbba70b86dd, 0x7cf6a6feba18d102, 0x056f53037d5d8efc]),
+    U256::from_limbs([0xf40fea2952071bd6, 0x8ad8fca543be01f5, 0xc3b89efad53df9cd, 0x1df4bc5d73239f5e]),
+    U256::from_limbs([0x7c734ab5ef658367, 0xbf2892ac87cd9c34, 0xd46e167d1fad1c13, 0x1ffb6ccbba001155]),
+    U256::from_limbs([0x06f2c26c2ecf2a24, 0x63fef91fc9448800, 0xb0abd647d353e51b, 0x08ee36e881496539]),
+    U256::from_limbs([0xe372cbc6cc172665, 0xce8aaa68487b9ee4, 0xa3e6283d5bab6f7f, 0x1c3080c254443332]),
+    U256::from_limbs([0x3e81b7b1af82af53, 0x47085b1660f8873b, 0x75a3cefa4db49f9b, 0x2dc6040500e7329f]),
+    U256::from_limbs([0x8cd4c216a89994b7, 0xe9385ce67d7f3356, 0xdb1f66ff2e82047a, 0x2c8bca9dd293bb67]),
+    U256::from_limbs([0x0c6c5469d401b7df, 0xc7a7087ed6eb8ab2, 0xf63648aaec1b9f61, 0x2a157b9786f9224c]),
+    U256::from_limbs([0xe61ff89c39bc40e9, 0x417e4e6522b472bf, 0xc42e7c26b9027b77, 0x1e0fb0cdd8a54591]),
+    U256::from_limbs([0x6794d464207d2b3d, 0xd6a42f7c00e2d7ee, 0x78bd73ee4ea4cfdc, 0x250144d1cea033fd]),
+    U256::from_limbs([0x983a2e680eedff65, 0x957aae0403116d22, 0x60443c4b78a8e830, 0x0b6b3fe9a7a525ca]),
+    U256::from_limbs([0x7eb650bc7bd616e9, 0xe52482fa32e70524, 0x9f4743b4963f8c53, 0x2fdf613e67d17656]),
+    U256::from_limbs([0xbe5bea66fbd7b38c, 0x06752827c3c04463, 0xfc55b3c0469cd893, 0x048f64b14275fb86]),
+    U256::from_limbs([0xc924dc9d6e854832, 0x6cdc16e690b6a4b3, 0x90e11ed4b7b494d6, 0x0f2027b781c2c524]),
+    U256::from_limbs([0x17d44ce2f88b0f91, 0x7cc3191f33b74ebf, 0x1c20c741524a2614, 0x29652785160b261c]),
+    U256::from_limbs([0xf6b058c4d359e179, 0x7fea130470aa44bd, 0xfd47810fcbe73af8, 0x1176909e02a5b37b]),
+    U256::from_limbs([0x5e1bc35c01e98993, 0xc2bf0156d636f225, 0x7baf0da58d069769, 0x180f26c6ce2a3a47]),
+    U256::from_limbs([0x4a86c7a6a05f083d, 0xd65f62601c1ca488, 0x5ad22e734fb7fe3d, 0x2afeedcc2cec5728]),
+    U256::from_limbs([0xbca26399103087d2, 0xb49d47a02e995a41, 0x98dbcd73dd751aff, 0x0dd21a87ee226269]),
+    U256::from_limbs([0x9da6a85aa7224962, 0x46d1c7db5d156646, 0x159fa8a0b6e0d614, 0x1d21baa09d7f50f0]),
+    U256::from_limbs([0x7a856351b48af802, 0xc014bea4236b1bc2, 0xc2506282cc5bb842, 0x2dc59c4c9e275cd8]),
+    U256::from_limbs([0xab9d5f755eb1f38a, 0x91c42d1447a41513, 0x19ba68a697f44f47, 0x2cf62d08541dff09]),
+    U256::from_limbs([0xd2264e7a0eb354b1, 0x83c97acd4b687aeb, 0xa9fedeea4047e460, 0x2f5b8853e2cd2e47]),
+    U256::from_limbs([0xae33f2703c470730, 0xc66eef9938e4da5b, 0x9170c391c5ba8db5, 0x05865328a9b2fd46]),
+    U256::from_limbs([0xca923c2aa8092b1e, 0x7b8f2725d327c6d1, 0x1b126be1dc0470a9, 0x29761c9fc3d2c4cc]),
+    U256::from_limbs([0xb01c2aeeb1d9a048, 0x3b96552d4bbb57fd, 0x515c37bc810d3a5e, 0x1d3fb1f8dc3c77cb]),
+    U256::from_limbs([0x7bb3b052c8705d90, 0xa0ceeb47b3f7c4c8, 0xee2f3a4d2964d241, 0x05f2fbeb7cae0a35]),
+    U256::from_limbs([0x88746954b97998f8, 0x19062154f4d7c132, 0x2a50257152609cc2, 0x1f445014a0f26608]),
+    U256::from_limbs([0xce39e05b38f698b1, 0xfc84bd1a4bfd7db3, 0x1ee37cfd9078c924, 0x2d35917cb1d0ca61]),
+    U256::from_limbs([0xdabc835775335ced, 0xe76716951c500fc8, 0x512f2f398b6526ef, 0x05a89a4eb40387b8]),
+    U256::from_limbs([0xf6c8633a94dd01de, 0x4f4e5bd5d7d53d5d, 0x4f46ced15594329e, 0x04e26aa3f0260117]),
+    U256::from_limbs([0x7350d939bac5b90f, 0xeb8730f2e2dfb7bc, 0x047e1dc6b6d403b9, 0x204d587d61526c1e]),
+    U256::from_limbs([0x75ea8280a2a420e5, 0x7c2cb53ed699f366, 0x6ae90a6bdae5843b, 0x21c8ca213d2f4444]),
+    U256::from_limbs([0x217787b46e822ebf, 0xd69e2e6a2c396716, 0x4e73af21457c46b6, 0x1303bd921f32fa4c]),
+    U256::from_limbs([0xc4d352097e5c8399, 0x45ace79c0e4433d7, 0x14387465317e713b, 0x0613823c5b6abeef]),
+    U256::from_limbs([0xb5046930f5ec55d1, 0x01f51144ce1ea89b, 0xf775e213e3ba12a9, 0x0f27c5305ee978f4]),
+    U256::from_limbs([0x8827792120c7bdb0, 0xea8a495115f5a538, 0x531950a307e07b30, 0x2cad11c693c6a627]),
+    U256::from_limbs([0x85959de3272f82b1, 0x3da97a9d4ae7d5c5, 0x7366618e46c6db4e, 0x16bb256883c44cf3]),
+    U256::from_limbs([0x45b00032e1d57a51, 0xfd67157a5ccdb7ae, 0x763695976410324b, 0x29afe50bd78f72cc]),
+    U256::from_limbs([0x9725aefe29aeb167, 0x07148609ab369813, 0xa726ae5ca35408a2, 0x09329902e59e5243]),
+    U256::from_limbs([0xacd0531ad53420fd, 0xdc10bf55c3314f42, 0x4776bc6af1764ccb, 0x04b1800e600c90ec]),
+    U256::from_limbs([0xe27276aa13b67ad1, 0x7ee9342a591b4330, 0x5e293c7a7dacedef, 0x298d0110bb90e4c3]),
+    U256::from_limbs([0xeee43929395e4f82, 0xb7ea4a350e510c07, 0x407e022649aa32d1, 0x024ea6e68cb1aa6b]),
+    U256::from_limbs([0x4dd934e1320a3d8d, 0xbd9f6835a0f0d03f, 0x5fa0116e135d2df2, 0x22ae3a1a81c4550f]),
+    U256::from_limbs([0x22705e681442ed37, 0xfe0115c1e199c28e, 0x2f4ae04d6fe09311, 0x1f0f5eee9e9abb2a]),
+    U256::from_limbs([0xf83e8cd35dac06c7, 0xe80c2fca32064bcb, 0xaf022464f350b4be, 0x023c86606616f317]),
+    U256::from_limbs([0xd6b2c86ebfd2b9d5, 0xf904bec4759b33da, 0x7d04cb03efe18c4b, 0x2a89c4e5b074fbe6]),
+    U256::from_limbs([0x70eaa7394a82727e, 0x75df2cf60b5fd114, 0xa4b9cf806075f9c2, 0x04b0f48c4f2e05bd]),
+    U256::from_limbs([0x0c931a7bd8cb349e, 0x8f107c99eb7c0290, 0x042a686f443417bd, 0x0edde5a2c01327ad]),
+    U256::from_limbs([0xc50672f137212cc1, 0x0675a8bd853aad1d, 0x4d8932080f9378b6, 0x2cacacc9c9d8b000]),
+    U256::from_limbs([0xa88cecaf07170dec, 0x1030e4b218e44c90, 0x56371156b0a3f773, 0x0816f33508621f2d]),
+    U256::from_limbs([0x53f4b76313c97920, 0x1ae9248608e2a52d, 0x9bffda935c8d3bee, 0x2ef8e0a455d472b4]),
+    U256::from_limbs([0xcaaf3ea7c0a334af, 0x2e3055ce408068ec, 0x605dd1f2dd18ebc0, 0x17184a8af9199aba]),
+    U256::from_limbs([0x0607130b44881448, 0x836436602777d81f, 0x60db6f78446cb995, 0x16395e153937a836]),
+    U256::from_limbs([0x437eaea9b8f5e2bb, 0xc32784c9d2f591e2, 0x440fc77307d6720a, 0x0e174088223c38e9]),
+    U256::from_limbs([0x865c65c31b47d402, 0xd0dd096140f39064, 0x2127573d56c8197b, 0x19e70fa937788f7a]),
+    U256::from_limbs([0x8d6bd7d21b03692e, 0x230beb344b918f6f, 0xbdab24780495ff97, 0x0e434c329d5242ad]),
+    U256::from_limbs([0xf35af9f55a8c22bc, 0x75ac157c7f3868ff, 0x42a211bf2ef3c158, 0x2c88a82eb87c81ba]),
+    U256::from_limbs([0x2e01c51d09ccf436, 0x5f33adf4cf5b49fd, 0x52a9c2de46938391, 0x111dc223e3c9fc74]),
+    U256::from_limbs([0x218af097f871baea, 0x393b000842e8baa1, 0xcb48f4539b507e45, 0x10647353de3197b9]),
+    U256::from_limbs([0x24354c07152f703b, 0xabce698525d0a477, 0xc1b3f07423582034, 0x0e53d64121c0a851]),
+    U256::from_limbs([0x6258ea92984b4348, 0x531081dbca4dec22, 0x3754969f4aa5a62a, 0x2891938acb83ee19]),
+    U256::from_limbs([0x57c3227532c12ce3, 0x62ba00c7c63ed8c4, 0x4dbe4bc53abc1702, 0x04106f95e4ba3fb7]),
+    U256::from_limbs([0x4d099f7dd3c19fe5, 0x92c5613ba25dd2ff, 0xb38b186f401eed0d, 0x2a73e61b8f1babfb]),
+    U256::from_limbs([0xed2281ab154f7c5f, 0x6e4856d6b21b729a, 0x3b798b923b8dd25f, 0x18e16b24642f1919]),
+    U256::from_limbs([0xc4dd875e12ae3485, 0x9f359a2d0f194030, 0xfc1a64467c555702, 0x0d5f849b166ab9f6]),
+    U256::from_limbs([0x0aa7cd7844dd0442, 0x523eaa93c1d70acd, 0xc63a4f1531b8b0ef, 0x0e25250c57365a29]),
+    U256::from_limbs([0x80761274074a599a, 0x335853c60188526d, 0xedfbf480b3d80b9f, 0x0f813d14af0c85bc]),
+    U256::from_limbs([0xe774377e78055435, 0xef2f469460f166a6, 0x24a8cebed6428083, 0x0d2356a92dbaae8c]),
+    U256::from_limbs([0xd77a43961e9d7dc7, 0x773ea1003cdd092f, 0xff56c8aad70b40bf, 0x1fb6dceb495bf90f]),
+    U256::from_limbs([0x9081627741c2bbe8, 0x2ab87c381cfc3c26, 0x5e23c5a323fd706c, 0x2c0a70ced79951a7]),
+    U256::from_limbs([0xa55e8382321dc5ee, 0xfd22db5e229e4c6b, 0xceb4b11037064ac5, 0x13dc6b3bdb05167d]),
+    U256::from_limbs([0xda6c90a47c35025c, 0x004eb3425d498025, 0x0bb88dbd7c0e6ca9, 0x210fa8b777907ec3]),
+    U256::from_limbs([0x0dd3c04e4d599cd9, 0x454a982668baf24b, 0x72fbbf80a410aab5, 0x0c5cfa957139b45a]),
+    U256::from_limbs([0xa873b6ef12e0f52b, 0xf65cedc06bb2fc93, 0x00fcf8bdb7be59b7, 0x1f1f3bc4c21d6334]),
+    U256::from_limbs([0x490374c0d67532e1, 0x933711644ee7bd57, 0xcd5e3fb30827057e, 0x188f0ee95a9dfb03]),
+    U256::from_limbs([0x2b80d0cfa726ec8c, 0x409727101b186cae, 0x77e8e0f3b44873e9, 0x0823877ebf8a842b]),
+    U256::from_limbs([0x3d98f1f0e61b1aff, 0x395ca2f3f5087828, 0x48ca6390d6f8bdc9, 0x0fe81603de39ccf1]),
+    U256::from_limbs([0x3566768ff1b83706, 0xccae35cabcaa1296, 0x7cebf41c134098f2, 0x2ef61fe192f52a9d]),
+    U256::from_limbs([0x4c2f8c44b1510f51, 0xfc6a38fef7f9ae1d, 0x4d56d2f66fab4d7b, 0x0db6d076b2dd1b9f]),
+    U256::from_limbs([0xcb0aa581831c7d06, 0xf279e6a0f8ca8bc9, 0x189f9c011d107b82, 0x2a194a4ae8583f28]),
+    U256::from_limbs([0xe7201718c8da8de9, 0x72f2eeaae2e500b7, 0xf48fb292f9188c9e, 0x0ae539fa9ca67d4a]),
+    U256::from_limbs([0xd12bb4c6dc2bb4d7, 0x11faffd4605b36cf, 0x6a8baa6d05b1ace5, 0x23f66a894b1df4d2]),
+    U256::from_limbs([0x245669d9fe5ba707, 0xe64601f466b56d32, 0xee9a4fb8017bf330, 0x137cef522af2942a]),
+    U256::from_limbs([0x0063a0bf92788c32, 0x221767e4634f0d55, 0xf9d64c1767789a6b, 0x187aafcb87841b2d]),
+    U256::from_limbs([0x6e52daf66c4cbd25, 0x38bb9a9851e13d7b, 0xa3647036ee36d749, 0x23212e75f4b89992]),
+    U256::from_limbs([0xd326de6b9af16d63, 0x489bb7d266212654, 0x9d068b2fc53abc96, 0x0f54cbe967770dfa]),
+    U256::from_limbs([0x2002d3cd51101fcc, 0xb81367955fde72a6, 0x359b8569ff3a219b, 0x23d83a60b9887ab4]),
+    U256::from_limbs([0xfac7dd66da5ebe76, 0xcb22f044607ee15b, 0x3ab0b0e189020d42, 0x2ea676fe7f21fc58]),
+    U256::from_limbs([0xde001f05a807890b, 0xf045029cb515f58e, 0x21a356ab56a16d41, 0x16f37f357a0c5848]),
+    U256::from_limbs([0xf18e4b7e47d13b03, 0xbe916e4c97d35a5c, 0x4288ae5811ed1311, 0x139093b78fc9603a]),
+    U256::from_limbs([0x55900f52778a11ba, 0x68d60bad285cc15f, 0x30c8bf246f41610b, 0x0aaba5fcad1c0470]),
+    U256::from_limbs([0xa6187fa0a2839efb, 0x55eaa0196cddc5d5, 0x1fc67dd3d37954a2, 0x1611827956f3435c]),
+    U256::from_limbs([0x818e41964b7d8126, 0x9e6d2bb59db30a52, 0x421e5f087ad16e0c, 0x23db56362f248912]),
+    U256::from_limbs([0xfe8f2e478224209c, 0x7331d19ea3de1dec, 0x02999c98f1af8fa1, 0x2f215e640207a88b]),
+    U256::from_limbs([0xd162b3d93eed8612, 0x78e9d3e1afb451e9, 0x2bce19021f8506e3, 0x2b2a261917c0fecf]),
+    U256::from_limbs([0x4324a0363e23384b, 0xf17b08f930f54bab, 0x2cb956df75b17b46, 0x2f662e4cdfef5f82]),
+    U256::from_limbs([0xa669eaa31f661a00, 0x25d426749a538b4e, 0x2c426c2ab1d65fda, 0x0870eeab2e7bb1e8]),
+    U256::from_limbs([0x725b15ac6a152fac, 0x409349d846457985, 0x5dc6b1013717c134, 0x169418dffc99f2ef]),
+    U256::from_limbs([0xec6895a7cabb4375, 0x0919ce6b90162e51, 0x39ad5bc535b4fbb4, 0x021d3cb240d64003]),
+    U256::from_limbs([0x8e34c3cc18024afb, 0x6fb19474c8cce4d7, 0x6addc149e4216eeb, 0x12e45c46c45b660b]),
+    U256::from_limbs([0x375a11b5abd03548, 0x152fd310b5d05970, 0x8c348d185403dc77, 0x0c7c86394b7ad61d]),
+    U256::from_limbs([0x4121b498a2fbb131, 0xc3e84c238d9c21a0, 0xefeb60008ff70d33, 0x3024e78b217f85d8]),
+    U256::from_limbs([0x0811ba318e1afc81, 0x9a3ed7679bb54ad4, 0xed3163c1ec718790, 0x235977101b56071c]),
+    U256::from_limbs([0x5a8ec383e1a73a70, 0xed79ad6fc6ba511c, 0xd974efdc0bc2d628, 0x14bf885e66f7c01f]),
+    U256::from_limbs([0x56548b38072f38d3, 0xf82610f1283f265f, 0x3e1d5891aa92ed43, 0x223325ae7e73cf09]),
+    U256::from_limbs([0x26f54870d318a57e, 0xb8025dadf0405592, 0x19f44560ef7c19d2, 0x239a5ae138dce6a0]),
+    U256::from_limbs([0xc99738f0b247672e, 0x48e01359c974855b, 0xfaf468d7c8a7d460, 0x01dcc35115d9057e]),
+    U256::from_limbs([0xa6d77b343e76bbf3, 0x269c282ba240cca0, 0x87a4deb5604a436e, 0x1359a40012c53325]),
+    U256::from_limbs([0x354d3798dc5003e7, 0x5fc8e31acaee0c07, 0x0c37c26a96f267c8, 0x0a9ee39712223d4c]),
+    U256::from_limbs([0x75391a84167e0231, 0x29868f7cd7c5e132, 0x88a9877233eeb5ec, 0x11812d1ca3671ae6]),
+    U256::from_limbs([0x9069e0590952f0b8, 0x98d40c0e28b6b597, 0x945d878960770f92, 0x2435cd699bc94985]),
+    U256::from_limbs([0x391f40eeab2af0a0, 0xf9aab2a1ea0d6685, 0xea5930cd5355303b, 0x2bf020785e4117a1]),
+    U256::from_limbs([0xbac2eda5c6758024, 0x69ac53e9b7439dff, 0xa76855292ca92e42, 0x2cef6c78577c9e97]),
+    U256::from_limbs([0x77b3b8d543135429, 0xafca506a5ce562c8, 0x60f05f029cdaa7c7, 0x09a30e00c9f0f6f2]),
+    U256::from_limbs([0x0165b9ba66a3e7cd, 0xbf9ae8063e0e8967, 0x9b264a74f121b90b, 0x1fd85928f8c8053c]),
+    U256::from_limbs([0xcaed4b674fdade91, 0x6950606df8cdf8fb, 0x62a6cd4524aa289e, 0x24482279d9efa578]),
+    U256::from_limbs([0x6ce6b27f36462185, 0xa455fc1de48c417d, 0xd8182a6951f9c780, 0x10260a39cfb0d82a]),
+    U256::from_limbs([0x474a37ae7d7e3148, 0xc97192a4420578d2, 0x89bb2698730d5c3f, 0x25207827a76052d4]),
+    U256::from_limbs([0x1edf6a6e375874bd, 0x771f15d97f2d5c40, 0x8e4f9e1a347df95d, 0x28e36b634ecbdf7b]),
+    U256::from_limbs([0x9f6bf66d46a1e165, 0xab7cc0d34babae85, 0xee3ba27c5b7f0064, 0x073f0dea6f83d443]),
+    U256::from_limbs([0x4c553ba9888c451e, 0xf87b9b0d169048e8, 0x65514437adbcfa97, 0x14c6618569f748ff]),
+    U256::from_limbs([0x1be6a2d45b4bbcad, 0x2d1f119a0fe6ef11, 0x4fa588a0b26fdc4f, 0x0c7675a042ba6cc7]),
+    U256::from_limbs([0xc955f09e495fb34c, 0xe1c29dbff250c684, 0xd8130c43cff604db, 0x03dfad7db3f32c6e]),
+    U256::from_limbs([0x80bd2cdcb35b64e6, 0xc1d9ec433efc6774, 0x8164fc6d4144200f, 0x20114281fbe35c18]),
+    U256::from_limbs([0xe4b998955da8a21d, 0x3df08a2c4a823a3b, 0xad06a48e2292c0fe, 0x29e42f5218b1ea3f]),
+    U256::from_limbs([0x9be11e17c2352816, 0x6c7c80fad2726d6e, 0xb6a67f256296daeb, 0x13bf4039488d94b1]),
+    U256::from_limbs([0x83c2938c227ee3fa, 0xc9c17a464e6dbd20, 0x3de58fa4662a9f88, 0x1847a4e36b3eace1]),
+    U256::from_limbs([0x4e295ca7663ada27, 0x5f51f6f8ca5d1a34, 0xaf9fe3c682683014, 0x0a7a2056c7b79384]),
+    U256::from_limbs([0xd6c94993bb7de86d, 0x6a45962d4979dfc0, 0x940cff2bc3216ff0, 0x181278caa15bdfb5]),
+    U256::from_limbs([0x11520c8f10e57a43, 0x789cf3f9f641dae0, 0xa36d390fbb1485fb, 0x0a8a6f4fbba2ee09]),
+    U256::from_limbs([0xa37514435e987cea, 0x9c067b6841fc833a, 0x87fbc6874520e332, 0x08f6b4c0d7bd5e4c]),
+    U256::from_limbs([0x10424ff0f2817df7, 0x13dd914b0c7d5232, 0xa9a91039683a55d8, 0x2206b2c18f3292e7]),
+    U256::from_limbs([0xcbeba3f487329cfc, 0x59df57be8e93291a, 0x32da26405a16cdd3, 0x0186cef5c04b3a6c]),
+    U256::from_limbs([0xbd1204fdcde772f0, 0x33c6080b6d15a5be, 0x7c79c9114c7a10d5, 0x075aff67570ea17c]),
+    U256::from_limbs([0x35700be66c1655a8, 0x2c97389caaf7f97a, 0x3869d2e8b25ac15e, 0x2b06d8389ba9621c]),
+    U256::from_limbs([0x7a18055acd737aeb, 0xc0e95dede804b2b4, 0x28161cb5228a94e7, 0x04fc6d0a6b677444]),
+    U256::from_limbs([0x8a2c3bc6ad25e69b, 0x124e58c234448a02, 0x71394d41ec2a8f4b, 0x25e4a295b4e7a2dc]),
+    U256::from_limbs([0xb6ca9cb6cba067b0, 0xa54301439340e889, 0x94fda907b639a7b3, 0x0094c24ab01c6db5]),
+    U256::from_limbs([0x13e7254d2a514549, 0x8850745ec6cbbea4, 0xcd6bc3cd5d98cbae, 0x2751821a6e3b2206]),
+    U256::from_limbs([0x8e97d8b817904bb9, 0xd5b42df025ecbd1a, 0xb9a8d77149b2419e, 0x145aaecc24e3878d]),
+    U256::from_limbs([0xda3403f9a95755f2, 0xb5e5151c57e4c163, 0x845ee1877b7aa059, 0x0d2aa00cc6e5167d]),
+    U256::from_limbs([0x6090c2bd08a764c2, 0x3c242b69ff6e3d4c, 0x49ebd36e4420ddfc, 0x24233b69861c70c4]),
+    U256::from_limbs([0xa76dc056a9895667, 0xac4184ba1927dd56, 0x826f762f836d5645, 0x2c55fe1a75147c9c]),
+    U256::from_limbs([0xed2d67b9e49f098e, 0xee1a78b64579de93, 0x654016674978b8b6, 0x19779b1cb16a9c79]),
+    U256::from_limbs([0xe50bd9376c5a22a1, 0x0bce692cfc00102a, 0x76ff60120c1f47ec, 0x1807721ba60fbae4]),
+    U256::from_limbs([0x167bd7d4b03d76f1, 0xe00ada708f167d4b, 0x802fbdc3bf12eac9, 0x1c0208f5aba12750]),
+    U256::from_limbs([0xbddb9127d3c273be, 0xbe888d21021f29d2, 0x8869abe2f5dbfb9f, 0x090b6e28ff541cc3]),
+    U256::from_limbs([0x5214c8665ecda01b, 0x96a8dda8c90887d6, 0x59a20010a799c7bf, 0x193d6c7e76ad94eb]),
+    U256::from_limbs([0x4ea7c4753af8a8d1, 0xc7e7540cda285a56, 0x4f1a7a102c1ba12e, 0x09ddd5bc0b1f8d4c]),
+    U256::from_limbs([0x49e16811f555be1f, 0x68721da2f9701395, 0x9cfe2a2878018171, 0x300a1dd5fda15d1a]),
+    U256::from_limbs([0xe152706e493cf470, 0x5a1ee181ce492016, 0x75bb41cfe0740e1e, 0x1520908df2eefba0]),
+    U256::from_limbs([0x0689e7cf30137846, 0x33ae9518f9450f48, 0xc682698ebcb62282, 0x0b3f215d52e85ec3]),
+    U256::from_limbs([0xefeb1954daa8a4d7, 0xb4972bfcddb194a0, 0x56a8ee2f736650de, 0x050996a07af6765c]),
+    U256::from_limbs([0x399dc4f1ee807add, 0x8cd9608e44138686, 0x3b5192c18ed06cc5, 0x23a59326daf5f94f]),
+    U256::from_limbs([0xf6b8bf967ad56496, 0x03976e815c0cf7d3, 0x6898ce834b1bdc20, 0x1d220b9add059286]),
+    U256::from_limbs([0xb1b1247104f681e1, 0x477c504e76a38a82, 0x6d36566738658923, 0x19b49c4da3d9e3a2]),
+    U256::from_limbs([0x5c37dd285beaae7a, 0xe1f25d5b7ac55444, 0x5bd6f3cf006c05b7, 0x139decc567b06b49]),
+    U256::from_limbs([0xbbb21f90f8e5b425, 0xe3099e99af3080de, 0xd4cd6e9bcd00c009, 0x1a5a7d8bca8d4f07]),
+    U256::from_limbs([0x5f98fd15983a809e, 0x88c93274c8549e62, 0x84d0a8be41e431d5, 0x0621e3811d3dcb95]),
+    U256::from_limbs([0x5bff97e3785ed6bd, 0xd4a3b0c3aded3863, 0x4a0130a021daa6ef, 0x02a41c2d2bcf1466]),
+    U256::from_limbs([0x4d84471eedca399d, 0x5355416e2aae1908, 0x94947cc3bda33d81, 0x21cfb54ba72fc067]),
+    U256::from_limbs([0x9975cf01b7c85dc6, 0x83cbe0ece741160f, 0x02a4cf8d0aea329d, 0x05115093894089a2]),
+    U256::from_limbs([0x1470c65fc070dfb6, 0xa1c06a6f48c97429, 0x12827129559ab422, 0x2e40541caca76532]),
+    U256::from_limbs([0xbb7f9cda1157600e, 0x27970001aa63e46e, 0xb60778900b60300d, 0x07eeadd730284f5a]),
+    U256::from_limbs([0xf4fd60d3333bd98d, 0x324d24d3f4858407, 0xf2051f8e2e8448d3, 0x0cf33e4964d350ad]),
+    U256::from_limbs([0x75709edf6a3bfcb9, 0xb65ef0b99df4acfb, 0xf1a2bda92c1e1c28, 0x287c60483e2db313]),
+    U256::from_limbs([0xebde0b5f6d9bb4a5, 0x139c1a1ecef53c3f, 0xb22661d0742e9695, 0x289440f4b7fdb8b2]),
+    U256::from_limbs([0x817ef2e5b0bc7208, 0xe8b9eb25222f97f4, 0xa24555a7abeb36d8, 0x291bda2c37fac962]),
+    U256::from_limbs([0x6ecc315119f42b73, 0x94ff99d04cea894d, 0x4cda7b82f14d5275, 0x0e10544958941240]),
+    U256::from_limbs([0x836be23e41ec25e5, 0xb7ffa8dc8cf60a1f, 0x123ae7eba6cd4522, 0x0e6097f0ad418ac7]),
+    U256::from_limbs([0x4c3e406e33e0def1, 0x4ff52c1445ad41b9, 0xa8a8069c62d307aa, 0x22fdb676724017af]),
+    U256::from_limbs([0xbf631c3aa92160f1, 0x2c5338817aab7ee7, 0x0f6950742b19e60c, 0x0204d594ad7855ad]),
+    U256::from_limbs([0x76cd76c3b2f7dc1b, 0x8c8fc11d868bc72e, 0x77110517f7b33651, 0x0b6a643dad3f9538]),
+    U256::from_limbs([0x0942639447cb43d3, 0xb7d76353a10bd07d, 0xfa6349adde17027e, 0x04189ea6c2bd4b00]),
+    U256::from_limbs([0xf7f1e13ea2deec3c, 0x2e14935134f8c3ba, 0x4b2dfde263036cac, 0x23bc8449d898a752]),
+    U256::from_limbs([0xd4c99c7a42107a16, 0x2576dba257fab323, 0xc64d39e0b2593b74, 0x0017efa608d9941b]),
+    U256::from_limbs([0x15ad0b9f2f92e2b4, 0x16f91e3a34a5856f, 0xb3877f9308e7758a, 0x02f061475e9d8888]),
+    U256::from_limbs([0x6872fe1ef6d52e25, 0x35da7cb0cba31868, 0x4a061330d73d6ba1, 0x1f257d4d6f327767]),
+    U256::from_limbs([0x9b9cc61c384853ab, 0x9cea488edbfd80fc, 0x158884bd23771514, 0x195340f6c1f7b5f6]),
+    U256::from_limbs([0xbed633ede031aa1d, 0xc1008f942459d5cd, 0x860b26a20b2c0853, 0x273e200179c5c45d]),
+    U256::from_limbs([0xbdbe01431c5e4a48, 0x6e2c8e02e808a158, 0xaf0f296c0e792d67, 0x24bd0ecea1e1df97]),
+    U256::from_limbs([0xdf38e82104bc6835, 0xb41ab671599d6350, 0x404599d458af5c6c, 0x0c6e6f0f7c20811f]),
+    U256::from_limbs([0xd80370d8d19683a2, 0x06bc08d58f6a3ef3, 0x8e129b5611fa9537, 0x1dee1352470235cd]),
+    U256::from_limbs([0x80725a12fa12ad4c, 0x6afddbfb72658780, 0xda7c757ac0c7e5e1, 0x1be0cc30343a168f]),
+    U256::from_limbs([0x0eb76bbd9215d984, 0x07ca8d9c61920858, 0x4c217c6a042a2311, 0x1a449abf644f68a6]),
+    U256::from_limbs([0xfaa3c5fecaac1789, 0x2daf476a9a2ca018, 0x459f59d90e64babe, 0x080117dde58f228a]),
+    U256::from_limbs([0x2c809de318d6d176, 0x7d15166fab51f2b6, 0x0f2c60bff098d5eb, 0x24ab9a167b61855e]),
+    U256::from_limbs([0x84c93eb0893784f6, 0x823201f71b54bda6, 0x03363292bccdc80e, 0x2b56aca9d5954605]),
+    U256::from_limbs([0xe1e4de0162a41b5a, 0xc95a12afbfc48ab5, 0x080ff1cdf3467d57, 0x057361ec23c87b30]),
+    U256::from_limbs([0x362cc14145089f95, 0xade3689ba1378cd9, 0xbbfaf4f204d9921e, 0x1baed177177a25ec]),
+    U256::from_limbs([0x808d75f593a71481, 0xe0e0309a841849fd, 0x656889c312975fdb, 0x00ccdb6d5f4913d7]),
+    U256::from_limbs([0x9eff962515561910, 0x839929df98ee743d, 0xde528471cc1f0ee7, 0x2c52f5e1ef57de5d]),
+    U256::from_limbs([0xc9052414ad7f7555, 0xf54b2ca3d53ce13f, 0x1ca48dad3a2fdd3f, 0x0234a4a32f767f2e]),
+    U256::from_limbs([0xfdd80776558e3433, 0xa9cc62abcc90a89e, 0x1990b472278f794b, 0x182b4c0c551ab0ce]),
+    U256::from_limbs([0xb9550e1846e53fd5, 0x1fddf19edf10d7cd, 0xd7d1a4eb76db4de7, 0x001ab2bbdbd043d2]),
+    U256::from_limbs([0xa256ea5249978358, 0x07b41e90e7c2d43a, 0x3a762a628e249409, 0x206e6cd1e3154c11]),
+    U256::from_limbs([0xd46ba95dd03dc8bb, 0x8a261bb175137177, 0x7bd335bdaf829879, 0x27816cab4bec1857]),
+    U256::from_limbs([0x39d3e7b0f585bc8c, 0x9526b2ad82cf0b88, 0x1ed64ef589440b3f, 0x1680b632a04be3eb]),
+    U256::from_limbs([0x4c3724c4fedd6e75, 0x0c75b0342511efea, 0xdf8234f58c027c19, 0x196b2cc0e6e07628]),
+    U256::from_limbs([0xbe38fe6f72647486, 0xc693770c38bf91b4, 0x684608acc6bf3b5f, 0x29ab0b6065ebbefc]),
+    U256::from_limbs([0x5515b727e540a859, 0x56ede18c5efcb8e2, 0xa2d65eccd9deb480, 0x224ce814d39c382d]),
+    U256::from_limbs([0x4fd0fbfefa66559f, 0xd1d42559b6a36826, 0x57b2c719b504f5fd, 0x195e1cb28f38a9cb]),
+    U256::from_limbs([0xe98ee3848ffb7840, 0xcdd58acdf138785c, 0x6dbd1c0ee1d6724c, 0x0e9e5eb70f4490ba]),
+    U256::from_limbs([0x919582226cbd5b21, 0x548cf71f46fbeb51, 0x4412ec653839d781, 0x1995c9c8e520014e]),
+    U256::from_limbs([0xe198fc377f26e324, 0x1008982f27104afe, 0xa653000283a6eae1, 0x0886095cc376c87f]),
+    U256::from_limbs([0x565f9f52369c124d, 0xb0d9a36d486aa115, 0x3de2342bfca860f9, 0x13761e206b3e89b2]),
+    U256::from_limbs([0x42c10f51f0dfab81, 0x2506ec0d508de259, 0x0a47515c519ad6a7, 0x22a8c6cfc0e38a50]),
+    U256::from_limbs([0x465a5ec7bebdee03, 0xa3086938b13f8ee9, 0x6962e4e27cf1aa4a, 0x18985e9c7ea6f1a1]),
+    U256::from_limbs([0xe0d15492845b31a4, 0x406a2abd64081043, 0x2eb148c00027d1ad, 0x215b523ce3f055c9]),
+    U256::from_limbs([0x2239d76448588c31, 0x98801b3e4019cdd4, 0xb885726b7df2e702, 0x0102f78c7513b093]),
+    U256::from_limbs([0x4e8dfa1b0a4ae727, 0x058f9ace11fdabd2, 0xab8885891ad44045, 0x0bce88602fd3a0f0]),
+    U256::from_limbs([0x1129c5481f52fa4b, 0x0b6227517f520025, 0x21043e2292e6cbb2, 0x0745f760f8d466fc]),
+    U256::from_limbs([0x5a2a262de4e2ee56, 0x98a1530fcca99153, 0x3cbdf7b174c410a9, 0x0406de3807445c6d]),
+    U256::from_limbs([0xed7a0117887d1acd, 0x3cc27cab59e887f7, 0xdbba66f784f87e24, 0x26779e7346349068]),
+    U256::from_limbs([0x1db75014267949d8, 0x17259a1d914a6ea4, 0x574c7c5c77ffd98b, 0x25150652a08af8ca]),
+    U256::from_limbs([0xa837ceab795fcc6f, 0x6e67de6fc3f6b65c, 0x74a79ed42202c5bf, 0x00bb8d13438040f6]),
+    U256::from_limbs([0x5a801067639ccf2c, 0x7f66eff6229d045d, 0xa192dd50ee25c006, 0x0c1ebf40eeaac3d4]),
+    U256::from_limbs([0x0d4951932ff20727, 0xcab86947aad12d49, 0xdd793754f76103b5, 0x1a39731eb32fdb51]),
+    U256::from_limbs([0x3672a0e857b960f8, 0x6988524edded3694, 0xc463af1662ecb28c, 0x2baad3d41b18ae10]),
+    U256::from_limbs([0x8cdd3b7e49af67e1, 0xfe82f4f8df1b5c21, 0xad2e833e41466fb2, 0x2e778da1518b6a4f]),
+    U256::from_limbs([0x81698645f8b16dc6, 0x634ae049b53adab2, 0x167743911e9bf4d4, 0x156dc717930d6490]),
+    U256::from_limbs([0x9349d908b23e231c, 0xf5e0104f9e528132, 0x9dbdc51db4968b45, 0x117f2ef1712692ab]),
+    U256::from_limbs([0xaa047b2f88ba68cc, 0x8d6ab08330f47512, 0x92e2d83b5087b6a0, 0x0b281b5d9dd2ca1a]),
+    U256::from_limbs([0xc0942ccb75d40767, 0x916f2a2dd640b6ee, 0x67a3366061764f8c, 0x1c2803766dd90965]),
+    U256::from_limbs([0x6f34766b7b6575a2, 0x668bd55800000aab, 0x141bf40e4c705683, 0x120174d53ba57d1a]),
+    U256::from_limbs([0x66e7d8b8ad376d46, 0xd01795d31b5ed734, 0xa5536de54c722e47, 0x0c37b25020f8c76e]),
+    U256::from_limbs([0x2500228c44fa91b8, 0xd8398025a4000a5c, 0xc30ba27651ba1c3a, 0x0a508f345cc9a8b0]),
+    U256::from_limbs([0x40f61f1823cb2a8d, 0xcb9497eeacc847a3, 0xc372f7e065840783, 0x12afa49ff4d8b4c6]),
+    U256::from_limbs([0x0730b01dd5071a55, 0xf4f7f6e7772f443e, 0x4809516b7aa3c716, 0x29fc37eb5ec7cd00]),
+    U256::from_limbs([0xe52a271bf84dd1a8, 0xe57dbcbff7514ad1, 0xfdc5159490db2977, 0x2048fb9c1fdaced0]),
+    U256::from_limbs([0xc8a4d02550fab64f, 0x3181f1d5e03dbc11, 0xe899bbcd0314a474, 0x00a6c4223aabe583]),
+    U256::from_limbs([0x55cff745686764d0, 0x0cca93c8ed68a356, 0x57e25d9c22d8dc6a, 0x15f462ab5b21c315]),
+    U256::from_limbs([0x079b2d1b9e4271ef, 0x1472e7e3bba0d35e, 0x3730197124be559f, 0x27bc1c9af68edf8e]),
+    U256::from_limbs([0x39a3a85968ec6ebc, 0x46929c71eb82da31, 0x38c0d3d6efda64e1, 0x1a2ec148f900eeae]),
+    U256::from_limbs([0x7eabcaea8904c22e, 0xb71b2354bcd1c55d, 0xd0616545922f2030, 0x04ff41cb553ca616]),
+    U256::from_limbs([0xd38712e03a0849b7, 0x14a984619260a7b3, 0xd6add67009fc237b, 0x2f2c4e7b18a845fc]),
+    U256::from_limbs([0xac53a9c69d84c0ab, 0xea6a140031106826, 0x1d5c7122f8e1a8ff, 0x22925c606a1f091c]),
+    U256::from_limbs([0x232b329c5b4e7279, 0x8811633f77822d7c, 0x66c257573ca2594d, 0x10e2a8c7b12bf702]),
+    U256::from_limbs([0xd586bce31ec7184c, 0xfa45b3548cd3f162, 0x92524bf8e488b783, 0x1712319595ae4649]),
+    U256::from_limbs([0xe43daed4dcda5c0b, 0xf279ff19454a64ad, 0x8ea5a1cb56b4c161, 0x2e9616987d333338]),
+    U256::from_limbs([0x8fc1f03acfbfe4ca, 0x12fc6137cf5b35a6, 0xd9a8392a2805ea16, 0x2c68210d75660df5]),
+    U256::from_limbs([0x0cb0f9bbe4e8adc2, 0xb0b3533061a35bcb, 0x30b6e1f637d23c67, 0x053cf3707283df87]),
+    U256::from_limbs([0xd227c059ddeddb66, 0x7081c0095decdce3, 0x769dacc79607b989, 0x07577491483d03d7]),
+    U256::from_limbs([0xbf90411312de4a12, 0xb95eaf855fc26ced, 0x81c5b256ca6b2d46, 0x05639b2324c0b0af]),
+    U256::from_limbs([0x0bd94e9b7f8f16d5, 0x1cea1ff487ea39d7, 0x52f3fd13763714f4, 0x0ec90944eb8fc36c]),
+    U256::from_limbs([0xf703b93665f5ea96, 0xaf303d6fb227fc68, 0x5bd3e755922f84af, 0x1e96cd3ac345bb2e]),
+    U256::from_limbs([0x24942da1e2e36d58, 0xb42ffebb0df885a8, 0x7d49858e6d5871e5, 0x0408425405c66baa]),
+    U256::from_limbs([0x534b022b081aa977, 0x26e0805aadb9957a, 0x3d41ebb0528b8994, 0x284d77566b42f391]),
+    U256::from_limbs([0x239427948d7d06bc, 0x26deb5624f644516, 0x337f58c7ca50a781, 0x0b044e5984887cda]),
+    U256::from_limbs([0x525063a6f2b340d8, 0xc0c3f71b58150c9c, 0x795bded1c2fc9674, 0x0486972179ee3496]),
+    U256::from_limbs([0xb68e5c79296e6bee, 0x7a7bfe406623e47c, 0xf9b72543b6033b31, 0x25b7568316c4b2fe]),
+    U256::from_limbs([0xa19269261ec6a8a8, 0xf6c5c15738aa5a54, 0x03ceff28a4d11275, 0x0cec9e74c3441987]),
+    U256::from_limbs([0x9b98130801d1a114, 0xa9957c9d736f0ca3, 0x97a65e8978194486, 0x2bcc34a99345174c]),
+    U256::from_limbs([0xdbed8d6f44b02551, 0x786940a1e8c558d8, 0x98c0f2be2d73e86a, 0x2ddb2ebc0ac98f64]),
+    U256::from_limbs([0x385ef7cdca80bea8, 0x43da183ed284ed7a, 0x480718d4d205351f, 0x2642b4a208e503e3]),
+    U256::from_limbs([0x2de3f7a025cb5087, 0xf71c7762f8bad04a, 0x64102ae85ccf1e6f, 0x23fabdb09b481bc7]),
+    U256::from_limbs([0xf495124cf70b823e, 0xf5f6c467480c8aa5, 0x9d327fbcd01dbf37, 0x0ae4ed0ea6adb74b]),
+    U256::from_limbs([0xed6e03b0b4492016, 0x4faf8adcc7c71bc2, 0xbef041afa774e363, 0x09034e1a646d6674]),
+    U256::from_limbs([0x83df4b3372b55ef2, 0x0015e0ca147b6506, 0x138dec79d84f9f6c, 0x2817ea20b471a4bf]),
+    U256::from_limbs([0x7f6d1dd2f1379ca9, 0x298397de78fd2aa1, 0x6bfa4e833e51e912, 0x2dfb7f81425c2def]),
+    U256::from_limbs([0xce894c24a8ef1f37, 0xd54c9ff7099f2bf5, 0x51d680be08daa6b0, 0x137b5bdb746523c0]),
+    U256::from_limbs([0x535243ddca21aa85, 0x024e382aebe02bcb, 0xf7046833dbad3fa1, 0x03900ec7adc8e28a]),
+    U256::from_limbs([0xd53ea508511cb555, 0x0c33df8f191551ea, 0xf2079863c759e6a1, 0x2b285b6c23b25c52]),
+    U256::from_limbs([0x1e84c375864613d8, 0x977e6fd12d8b3528, 0x2c32b79c464e2d6c, 0x1242067be2202d1b]),
+    U256::from_limbs([0x997db63b17525eef, 0xf3eee805237b158d, 0x9536ef2920abe7e6, 0x1e8690f5b0a8729b]),
+    U256::from_limbs([0x64a5b279ad9c13ba, 0x17a91f261ef9d817, 0x72c82edb369fb0f7, 0x047f5113e1287b55]),
+    U256::from_limbs([0x4d61c44743902105, 0x3593d173b687122a, 0x5a6aa58ae2cd3259, 0x2d8800f21cd6774d]),
+    U256::from_limbs([0x79bad813756f84b0, 0xdeef088685d19769, 0x80647f10d1a4c4ca, 0x274ba1521d5bc5c5]),
+    U256::from_limbs([0xe7fe4934c8b56441, 0xddca8f1eee9d8d56, 0x310d34a0737efaed, 0x17895b7c10d6a475]),
+    U256::from_limbs([0x761bf61e7dc13440, 0x8cf5becad2f66d15, 0xced09b1a6db25897, 0x06eed11ae9f70df5]),
+    U256::from_limbs([0x84e2db5ded748f09, 0x8842cefcbcd2c6bf, 0x3599e37f6a5daf92, 0x3036b521c631ce5d]),
+    U256::from_limbs([0xd7d7805a6367482d, 0x645c90d72e367609, 0x5a6fbf168c818478, 0x1f52d6e9ac28976c]),
+    U256::from_limbs([0x23c1596cedb35e6b, 0x9c3dd871091e96a3, 0xe1055932b3dd9ef3, 0x2a0e60356875d6ac]),
+    U256::from_limbs([0xe1830dbe5311c38f, 0x7f434938fdebb9c0, 0xd38eeec2c8f54d0c, 0x02089633a6194f3e]),
+    U256::from_limbs([0x336bac1240b604c2, 0x57cfef0612baa963, 0x5383283fdb0d7694, 0x178c73d2769765d8]),
+    U256::from_limbs([0x8c4be5004afa19a0, 0x7d5d0db849e789f2, 0xebcdd710e3b34183, 0x2fc20bcaba9e3d96]),
+    U256::from_limbs([0x2354c01f0f4a722c, 0x7e0f21494982df87, 0xd5ad279f425213db, 0x151a423bee67fb14]),
+    U256::from_limbs([0x6895b31eebcdf47e, 0xad0f8e25c2073536, 0x4e10e4afeb4f5578, 0x25d61619e135690c]),
+    U256::from_limbs([0x9f2c3a664b045cd7, 0x32bf9f36c51277d0, 0x301d7295d3e685d8, 0x15dd579bbd075429]),
+    U256::from_limbs([0x032b7283bcc959b1, 0xf4423c13cf5b8cc1, 0x109fd361c1ca90ad, 0x236fa29b81678c8a]),
+    U256::from_limbs([0xc5baea3446c01d1d, 0x20537a977da10e53, 0x368691bf1567cb73, 0x2a80cbac9b884e20]),
+    U256::from_limbs([0xfffbde85fd912378, 0xc0c179b0c22b2c13, 0x28a3b3f4d27f276c, 0x11d4cb8957937b36]),
+    U256::from_limbs([0x0fa54eadab402946, 0x0a52fbeb1637c501, 0x2a07c9b71efc0f80, 0x1289bf8ec507bf75]),
+    U256::from_limbs([0x2fb155466da6f303, 0x4903e77d2ddc03ec, 0x7d25799f2f03dc9e, 0x26f842b1a9939429]),
+    U256::from_limbs([0xbdd5f09905c3a369, 0xcc40eadc48d37d39, 0x14e878fa2f9e091d, 0x15944b9954b61823]),
+    U256::from_limbs([0xa0012f584426fadf, 0x93928f84442c7465, 0x76a6bfe0f1fed137, 0x0742166dcd8ebd85]),
+    U256::from_limbs([0x44cbd735707b68ff, 0xde792e72fd61c063, 0x53b56b05768fd50b, 0x08603d5a0e937b53]),
+    U256::from_limbs([0xd25b9777edb74a75, 0x0bf58d86032d03bf, 0xb6f25e21a31683d8, 0x0caa1711c66e9301]),
+    U256::from_limbs([0x3db345f3fe74a311, 0xfab00c32ae45c75b, 0xdff87b5f636ee676, 0x2d6506da695d2087]),
+    U256::from_limbs([0xe976b8b300758c84, 0xd75dc4cb68533606, 0xeff6f84049cb2e01, 0x0d9244b07af9b6ea]),
+    U256::from_limbs([0xa37acd15cb1c8c46, 0x3f030ef6a1f5401a, 0xdde3f1c3b432042d, 0x243abc3c3382faa5]),
+    U256::from_limbs([0xf8e1bfe8efe40198, 0x024727d3b0791b06, 0x0eda3989242e0723, 0x168416e0a6d275d3]),
+    U256::from_limbs([0xa491681d9c5f8eeb, 0xfe634aa14cb11ae3, 0x0ea80c65688654af, 0x1bcdeef03693d1ad]),
+    U256::from_limbs([0x7c231107e5653909, 0x551bef58d94fad7f, 0xedb0ab8bf7eb1c4e, 0x02f66bfda3d2a1bd]),
+    U256::from_limbs([0xafe9a7b94298975a, 0xeb8ac1fd0a1ed349, 0x5922f023fdecc097, 0x019f6802171ab82a]),
+    U256::from_limbs([0xf508c949ad52c3ae, 0x2645a1dd862d87c1, 0x8c760468a0556c23, 0x195a1c738398feed]),
+    U256::from_limbs([0x24ed1b0fce37f1e3, 0x6f24924810e838cb, 0x67dfae1f853f360e, 0x0a36a3a46e6ec165]),
+    U256::from_limbs([0x07cd840c09f374ae, 0x81bbc3974c3c17c6, 0xa7b35c25f9e8dd3e, 0x002123c29ea87f7e]),
+    U256::from_limbs([0x6e42b3985789e2d3, 0xf3005b80e93a520b, 0x71f0f0352b0f5bfe, 0x02a717499cb6c31f]),
+    U256::from_limbs([0x27aaa8c8cfc4daa1, 0x2fdc933e9ba90fd3, 0xa2293e18baa309c5, 0x0fc053584b0a4607]),
+    U256::from_limbs([0xef7b494794855541, 0xb1b99ae3c6d71baf, 0x32e87a86f4573032, 0x1310d1942129737b]),
+    U256::from_limbs([0x40969eeb49e0b8de, 0x18e6b2a6657e74b5, 0xf5761c558fee5e12, 0x1bfadae53fc82900]),
+    U256::from_limbs([0x42d32de8677556ea, 0x650c5b6afb392da1, 0x60ff2c60b7579395, 0x28c08a69e5f36414]),
+    U256::from_limbs([0x3d9d9d8b2622d909, 0x040f47fe8cd92216, 0x7f44d643ed6a3f2b, 0x223222ee42b6ca99]),
+    U256::from_limbs([0x031cb125588876a0, 0x1448b43949345690, 0x29bb0842d2c452a7, 0x064dad4752e708ff]),
+    U256::from_limbs([0xb8befd35411056a5, 0x82b71e9a1bc5919d, 0x6b22dcabb9a1ba6c, 0x2d5ddc2179704399]),
+    U256::from_limbs([0x1e83fa21069c0e0b, 0x0bcdb22f926d6159, 0x6ec8e9f6600f5a6e, 0x26315fcd9b5e1a26]),
+    U256::from_limbs([0x4b0a1331bc8371ee, 0xd4f22e82f346aa75, 0xab23da38b478faf2, 0x0759273a4177fcde]),
+    U256::from_limbs([0x34a0b9d1478fcf2a, 0x6ddd84c364d6279d, 0x8fa0a840ea972989, 0x09e13300eade7056]),
+    U256::from_limbs([0x145cbe0240d4f8bc, 0x34b22f8cc054463a, 0xb5b7bb954f278f24, 0x13e97ed3c16fad6c]),
+    U256::from_limbs([0xf61c272c5aa51ad0, 0x027cde5765971bb1, 0xb221bc2cbedb8aa3, 0x05cd3486dca58f4e]),
+    U256::from_limbs([0x71a3b2b6149c7de4, 0x65e1c1697816a9a6, 0xf1df468b12fd3e86, 0x005ce523f6bc21da]),
+    U256::from_limbs([0x37abea482b2d8cbb, 0x1f089d671c83c5b5, 0x7cd7914028145fb7, 0x2103066cc5df55e6]),
+    U256::from_limbs([0x8173d2edd7636928, 0xc539f72329317aaf, 0x1ca0502eaccc1672, 0x039f8077bd9b71f9]),
+    U256::from_limbs([0x85dc3af7cadcc5c5, 0xaf3a6422e81b4f48, 0xb89672a2205346e6, 0x2247bc0cc4373705]),
+    U256::from_limbs([0xa20b2e819638df80, 0x426762706188790e, 0x0687a7e53c3ce53c, 0x27bcc768bd31b9d0]),
+    U256::from_limbs([0x36487368cfdfd708, 0x79ac350159814ea5, 0x2c51c73a2809732d, 0x0d91cef51a76f7a6]),
+    U256::from_limbs([0x8537f627ba140730, 0x67bad46d6aa1cd00, 0x8dc68dc02343a0f1, 0x20a9ecdc649a2638]),
+    U256::from_limbs([0x325dae2941f528bb, 0x1016220d39e0c230, 0x6dfc911d5cb80c1a, 0x23134149a0d4567c]),
+    U256::from_limbs([0xedadc03fe74b9474, 0xe5a5b0dbf05874d5, 0xc6f29ccf1c9cc4f3, 0x2a07507e9f030a42]),
+    U256::from_limbs([0xcf3aafc64966110b, 0xb2729b718ce81152, 0x8e54ef29f06f35fd, 0x1bdf66bb1d57fd78]),
+    U256::from_limbs([0xf5630b78e7e0d0b9, 0xbe619bf77a12020c, 0xddf50783bf158d51, 0x275c33d07f9e7338]),
+    U256::from_limbs([0xbf1ad234e8b28116, 0x2bfd0f32c4070b55, 0xa1ff1b3b5b7afb6a, 0x16411554e55fe604]),
+    U256::from_limbs([0xfcfb60831f6759a8, 0xa36c641bc2851a0d, 0x57249ca1a5c55222, 0x073e6ac7e6af2275]),
+    U256::from_limbs([0x8f31331b0556e12f, 0xc202bbc3baca0683, 0x748543c76a218f95, 0x10a6fde88c5f621d]),
+    U256::from_limbs([0x038d29dcf96b0eb6, 0xc97a1bcec23e136f, 0xa88128a072c8434c, 0x29c5865a26588ca8]),
+    U256::from_limbs([0xd516fcca07212c1a, 0xfea8c17ca64e4d5d, 0x11b1911cdd569576, 0x233e0ef6ad132ed2]),
+    U256::from_limbs([0x6bded3e4d2e0b840, 0x5b6adc238d878a47, 0x664408a29860365f, 0x096f9b92b9cf2c7d]),
+    U256::from_limbs([0x3192e395db785554, 0xd7b1f1e835de1fbf, 0xb943bb57cb90edf6, 0x2fc788fe8aad4c28]),
+    U256::from_limbs([0x0338d9689f6e023a, 0xef7c9d722bc648dd, 0x503795fc5e114076, 0x1bac0ed609233148]),
+    U256::from_limbs([0x9fc383643e2adc82, 0x94e561573d8c72a6, 0xcd148e106e3fc230, 0x001c35268aa030d6]),
+    U256::from_limbs([0xddcc95a7202e34b2, 0xa39ce39ff94fc167, 0x8b92837ffb27bfe7, 0x2bb1f8fe5d998d0b]),
+    U256::from_limbs([0xb8e821dd61f9a2b2, 0x422da23945630b10, 0x705b9af68cd0c145, 0x1a98576450a43e5e]),
+    U256::from_limbs([0x8388bcbdd50e6037, 0x6af966f123d653f3, 0x2ba9941043fb23f1, 0x2714d37ee506efbc]),
+    U256::from_limbs([0x3e50ea00cc2a4ad0, 0x1ff7ed75413917b5, 0xbcb8075a03f4a2a0, 0x175d0480ba14abf0]),
+    U256::from_limbs([0x17fc026fd084d541, 0xbe7709701ec8158c, 0x63761750dce08da2, 0x203de45a037b75ef]),
+    U256::from_limbs([0x1a7283022193abf8, 0x2560f76b01cc3d43, 0x85c329080d6329a1, 0x265aabfc78b70ca9]),
+    U256::from_limbs([0x127af2ea5f3a87a8, 0x2b2ad1520ac0819c, 0x2edaffac8658a6c2, 0x135468dfbeb70e8c]),
+    U256::from_limbs([0xfa9d9293b6711fdd, 0x286c61758447ff1b, 0x65a8a82001c84ce8, 0x216a41e24329deda]),
+    U256::from_limbs([0x0bb6fd0d4fe482e6, 0x70d1a27cdb21b946, 0x2bed8bb99ae345ad, 0x2887ed49797bd665]),
+    U256::from_limbs([0x9fae446b8ae49cad, 0xea53a9811e2424a1, 0xb6ee3c79dabd1892, 0x03e2bf82690c804b]),
+    U256::from_limbs([0x946649b1c4e81116, 0x67414676dd8a8ce9, 0x5cbe14dcc6156118, 0x0e6dce1bbe6e9e46]),
+];
+const T7_MDS: [U256; 49] = [
+    U256::from_limbs([0x1bca45f39d337e9d, 0xa15cc1625102ae51, 0xaa19cdfec726fa3f, 0x2abd9e16b7b48289]),
+    U256::from_limbs([0x8b63cad04ecb36c6, 0x7362ae381898acaf, 0xb691875300db5a0c, 0x1b4bc5885d1cfc8e]),
+    U256::from_limbs([0x1eaeb012751c7f83, 0x953695029eedbcf9, 0x3c5818571a424a54, 0x08a72fbb55dde3e9]),
+    U256::from_limbs([0x00af039b0abb83f5, 0x1b3d3763d8fc7624, 0x921892f83e500bbb, 0x0f95359a1f6845d4]),
+    U256::from_limbs([0xf82472fbce7770d9, 0x43e6c7b5dadf52d0, 0xafde34d81619defb, 0x16f01751ce985328]),
+    U256::from_limbs([0xc8af1f4d56596417, 0x55e8d885e3c07cec, 0xafa629f3e6d33e25, 0x1c391626328457fc]),
+    U256::from_limbs([0x56f03965a42c5297, 0x023ed5bac91c2c10, 0x3e3c642d1e0b8032, 0x00970f671b9ba35e]),
+    U256::from_limbs([0x1213fcafd6427989, 0x278b6c6b2cc57a87, 0x26bff032cd6a3158, 0x1fe9feb5c7f7c7a4]),
+    U256::from_limbs([0x0ff6e88b1a7b4bc4, 0xb8dec0fe9e568615, 0x5eac47a19f6437a2, 0x0cc7eabb154aa795]),
+    U256::from_limbs([0x76b5a990fea75ace, 0x15e429906ca0d150, 0x26ef9af79be8b6c7, 0x0393efd96399aa07]),
+    U256::from_limbs([0x5db47d39ac9e5955, 0x6eeb33f697d1b082, 0x030cf8a4f4377129, 0x0871233835b752ea]),
+    U256::from_limbs([0x0497b6e6504891cb, 0xe96a5fad1d2db427, 0xaea34fcfb26ca6af, 0x1a02798f047a51ba]),
+    U256::from_limbs([0x7dba4c664d5a0da0, 0xa31d6e3e4c3bcc65, 0xcb713c234ea79d06, 0x055665d76df3cd0f]),
+    U256::from_limbs([0x88fd7de3bb1052c1, 0xe7758286a44845ad, 0xc91887e302fc55e8, 0x26ee9691a638edc7]),
+    U256::from_limbs([0xcd53d0c4d433224c, 0x65d49e5a2d41556b, 0x2d217c5e31148211, 0x00cdb2db9e4f9d73]),
+    U256::from_limbs([0x8a131b70856d1cbe, 0x6a3a787c4bcd9c79, 0x182123f5c49306e1, 0x012f3bb29c972793]),
+    U256::from_limbs([0xdd2cd1f17ad73481, 0xd43fd1e0dbdc5528, 0x82b527677daf444e, 0x0bba9006404c5ede]),
+    U256::from_limbs([0x837ca070be84a2dc, 0xe17c9f84b1713820, 0x219acf1ca6390cc1, 0x17e4e73bf701f88d]),
+    U256::from_limbs([0x485015f73d53d207, 0x8ad60760dd60276d, 0xa343b8da5b20cc35, 0x234b8a077ef52b53]),
+    U256::from_limbs([0x7ae9a35a7fd45d9d, 0x835d7217a4c50a48, 0xc1cbd1b46e6ded5b, 0x2dc3fd77394b7b5a]),
+    U256::from_limbs([0x97f82c6ee2e61e48, 0xcf882f12276bd12f, 0x72b1c16ff0192e48, 0x1668477640fab14c]),
+    U256::from_limbs([0xc999895adeb4d70b, 0x6904b3877f2159dc, 0x44fc2b98347ea8b3, 0x09664216ca565c1a]),
+    U256::from_limbs([0xdf30914e4849ecef, 0xabf76d77305857b8, 0xba0cfa93e20fb685, 0x111fd8dfce2bd3b7]),
+    U256::from_limbs([0xa48eec04e4d93426, 0x3598265b1ab6774d, 0x3497a511e56f6675, 0x19f53c0a592b3ea9]),
+    U256::from_limbs([0xe6a541e8cf66a174, 0x8b1dbdf18dd1c318, 0xc3523b7bee5a6014, 0x19b683c39d3b002f]),
+    U256::from_limbs([0x6456c35311c1bd5b, 0xb8036bb3e882dd5e, 0x3e3448719c7ef100, 0x2547113a818779f1]),
+    U256::from_limbs([0x1ba8d5bc76943b0b, 0x6a3e35cba9a97044, 0x7bb1013f72ebd412, 0x00cf148f8045cdbe]),
+    U256::from_limbs([0x709076c00b869599, 0x038168cb28cd2e1f, 0x3322a09c3733973a, 0x3008421eed767a09]),
+    U256::from_limbs([0xa3c658d2f56493d6, 0xfe77b43c244c9988, 0xa16dbc115566aa48, 0x1463f51d4a4cb1eb]),
+    U256::from_limbs([0x250ed90118e8fec2, 0x31c0f83832a61b82, 0x006a59d292aea847, 0x16afd8f4c39038b8]),
+    U256::from_limbs([0x8172055333e07579, 0xdff8352711f95863, 0xbab996921936e9b1, 0x2296361f3bf154ef]),
+    U256::from_limbs([0x9beb92d909ec891c, 0xa198d1f8e3439be2, 0x9f69843cf8d5bbac, 0x1c3160ebaeb10f80]),
+    U256::from_limbs([0xa24912a7635404c6, 0xb5b4d414c6e09acc, 0xcd9213abe43312ed, 0x094c66bdcff54382]),
+    U256::from_limbs([0x5d94b853b1746f89, 0x434ab5bb84f3420e, 0xd004ff777d7c0938, 0x2e883bd408c4c826]),
+    U256::from_limbs([0x5f27cb3abba2030b, 0xa053f2f23c094b87, 0x3dd18fd89a6b38bd, 0x228af6d425e91a78]),
+    U256::from_limbs([0x65873df66ce51f59, 0x4dfceb1a1e6da381, 0x347c7f2a8f47612d, 0x214e41788bd2b990]),
+    U256::from_limbs([0xd4805272af9b9775, 0xb529d76f54e4b5d7, 0x873da94e9f4e84c8, 0x03fba82d3f783620]),
+    U256::from_limbs([0x1b4e46ed36289511, 0x785329dfb12ad9bd, 0xcebd7ebbf60fb435, 0x066782fe3e869de2]),
+    U256::from_limbs([0xdff51588835fb537, 0xe5d1643fceeb212c, 0x01efa37a27e6b7aa, 0x1c5cd6b23ef336be]),
+    U256::from_limbs([0x830bd2b311539453, 0xd9ff3fad9a794e15, 0x27248af094e29fee, 0x2f3b4bf0f105f88e]),
+    U256::from_limbs([0xab3a0a36fc1ff432, 0xf64729b311783fcf, 0xb739fc23f2fe4057, 0x2f4085eaa9f80a69]),
+    U256::from_limbs([0x16214c088185638b, 0xae361934078aa59a, 0x0fa1f98fe2507f6a, 0x2363603349e94f52]),
+    U256::from_limbs([0xfb715923bf0fc79d, 0xdff184a1957a9385, 0x128260899abe8fbc, 0x0548541724f64e20]),
+    U256::from_limbs([0x69cfd2de71b14f98, 0xeeb095680a729283, 0x59a205e5b01c811d, 0x21c5e25a6991364e]),
+    U256::from_limbs([0x55a2edac37663d02, 0xce8fff171300d414, 0x5817aff7792a2efb, 0x11c5cc53f6338c82]),
+    U256::from_limbs([0x96f9d87a39504bf9, 0xad8eef68f577da8b, 0x5c4ee7214aa0edd2, 0x27a0949fdc9f1049]),
+    U256::from_limbs([0x0455cbb5b0c230e2, 0xe556a0194e6d552c, 0x3c455ef0d0dbce0c, 0x1c7fc68f3fbde4a2]),
+    U256::from_limbs([0x0cbfb4500d63de9c, 0x8abc1ced0c3f3c53, 0xb3a2939fd33991dc, 0x11d0f56fc00e7d85]),
+    U256::from_limbs([0x91f332fb2bade44f, 0x6363bec886679cbd, 0xb048cabe1636f0e9, 0x01b2e9d20d9b55de]),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inputs(values: &[u64]) -> Vec<U256> {
+        values.iter().map(|value| U256::from(*value)).collect()
+    }
+
+    // Reference outputs of circomlibjs poseidon([1]) and poseidon([1, 2, 3, 4, 5, 6])
+    #[test]
+    fn hash_matches_circomlib_vectors() {
+        assert_eq!(
+            hash(&inputs(&[1])),
+            Ok("18586133768512220936620570745912940619677854269274689475585506675881198879027".parse().unwrap())
+        );
+        assert_eq!(
+            hash(&inputs(&[1, 2, 3, 4, 5, 6])),
+            Ok("20400040500897583745843009878988256314335038853985262692600694741116813247201".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn hash_rejects_unsupported_widths() {
+        // The block-hash layout has 8 public inputs, for which no parameters are embedded
+        assert_eq!(hash(&inputs(&[1, 2, 3, 4, 5, 6, 7, 8])), Err("Unsupported number of Poseidon inputs"));
+        assert_eq!(hash(&[]), Err("Unsupported number of Poseidon inputs"));
+    }
+
+    #[test]
+    fn hash_rejects_input_outside_field() {
+        assert_eq!(hash(&[BN254_SCALAR_MODULUS]), Err("Poseidon input exceeds field modulus"));
+    }
+}
@@ -3,7 +3,18 @@
 // The setup.sh script will overwrite verifying_key.rs with the actual verification key
 // DO NOT EDIT - This is just a stub to allow compilation before setup
 
-use crate::ZKMintContract;
+use crate::{VerifyingKey, ZKMintContract};
+
+// Empty verifying key: is_initialized() reports false and mint/verify revert until setup runs
+pub fn get_verifying_key() -> VerifyingKey {
+    VerifyingKey {
+        alpha_g1: [0u8; 64],
+        beta_g2: [0u8; 128],
+        gamma_g2: [0u8; 128],
+        delta_g2: [0u8; 128],
+        gamma_abc_g1: Vec::new(),
+    }
+}
 
 impl ZKMintContract {
     pub fn set_hardcoded_verifying_key(&mut self) -> Result<(), Vec<u8>> {